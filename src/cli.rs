@@ -193,6 +193,10 @@ impl<'a> Command<'a> {
                         || *s == format!("--{}", arg.long.unwrap())
                 });
 
+                if arg_index.is_some() {
+                    self.check_if_required_args_are_present(&args, arg);
+                }
+
                 let value = arg_index.and_then(|index| args.get(index + 1));
                 value
                     .or_else(|| arg.default.as_ref())
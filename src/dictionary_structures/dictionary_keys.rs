@@ -8,6 +8,12 @@ pub enum Comparison {
     Unknown,
 }
 
+impl PartialEq for Comparison {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Comparison {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -40,6 +46,12 @@ pub enum Declension {
     Unknown,
 }
 
+impl PartialEq for Declension {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Declension {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -111,9 +123,16 @@ pub enum Mood {
     Subjunctive,
     Imperative,
     Infinitive,
+    Participle,
     Unknown,
 }
 
+impl PartialEq for Mood {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Mood {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -121,6 +140,7 @@ impl Mood {
             Mood::Subjunctive => "subjunctive",
             Mood::Imperative => "imperative",
             Mood::Infinitive => "infinitive",
+            Mood::Participle => "participle",
             Mood::Unknown => "unknown",
         }
     }
@@ -131,6 +151,7 @@ impl Mood {
             "SUB" => Mood::Subjunctive,
             "IMP" => Mood::Imperative,
             "INF" => Mood::Infinitive,
+            "PPL" => Mood::Participle,
             _ => Mood::Unknown,
         }
     }
@@ -220,6 +241,12 @@ pub enum Number {
     Unknown,
 }
 
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Number {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -338,6 +365,12 @@ pub enum Pronoun {
     Unknown,
 }
 
+impl PartialEq for Pronoun {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Pronoun {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -378,6 +411,12 @@ pub enum Tense {
     Unknown,
 }
 
+impl PartialEq for Tense {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Tense {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -471,6 +510,12 @@ pub enum Voice {
     Unknown,
 }
 
+impl PartialEq for Voice {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Voice {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -503,6 +548,12 @@ pub enum Age {
     Unknown,
 }
 
+impl PartialEq for Age {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Age {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -519,17 +570,33 @@ impl Age {
         }
     }
 
+    // oldest first, the words without one age after all the others
+    pub fn as_number(&self) -> u8 {
+        match &self {
+            Age::Archaic => 1,
+            Age::Early => 2,
+            Age::Classical => 3,
+            Age::Late => 4,
+            Age::Later => 5,
+            Age::Medieval => 6,
+            Age::Scholar => 7,
+            Age::Modern => 8,
+            Age::UsedThroughoutAges => 9,
+            Age::Unknown => 10,
+        }
+    }
+
     pub fn dict_key_to_age(key: &str) -> Age {
         match key.trim_matches('"') {
-            "A" => Age::Archaic,
-            "B" => Age::Early,
-            "C" => Age::Classical,
-            "D" => Age::Late,
-            "E" => Age::Later,
-            "F" => Age::Medieval,
-            "G" => Age::Scholar,
-            "H" => Age::Modern,
-            "X" => Age::UsedThroughoutAges,
+            "A" | "archaic" => Age::Archaic,
+            "B" | "early" => Age::Early,
+            "C" | "classical" => Age::Classical,
+            "D" | "late" => Age::Late,
+            "E" | "later" => Age::Later,
+            "F" | "medieval" => Age::Medieval,
+            "G" | "scholar" => Age::Scholar,
+            "H" | "modern" => Age::Modern,
+            "X" | "all" => Age::UsedThroughoutAges,
             _ => Age::Unknown,
         }
     }
@@ -552,6 +619,12 @@ pub enum Area {
     Unknown,
 }
 
+impl PartialEq for Area {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Area {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -573,18 +646,18 @@ impl Area {
 
     pub fn dict_key_to_area(key: &str) -> Area {
         match key.trim_matches('"') {
-            "A" => Area::Agriculture,
-            "B" => Area::Biological,
-            "D" => Area::Art,
-            "E" => Area::Religious,
-            "G" => Area::Grammar,
-            "L" => Area::Legal,
-            "P" => Area::Poetic,
-            "S" => Area::Scientific,
-            "T" => Area::Technical,
-            "W" => Area::Warfare,
-            "Y" => Area::Mythological,
-            "X" => Area::AllOrNone,
+            "A" | "agriculture" => Area::Agriculture,
+            "B" | "biological" => Area::Biological,
+            "D" | "art" => Area::Art,
+            "E" | "religious" => Area::Religious,
+            "G" | "grammar" => Area::Grammar,
+            "L" | "legal" => Area::Legal,
+            "P" | "poetic" => Area::Poetic,
+            "S" | "scientific" => Area::Scientific,
+            "T" | "technical" => Area::Technical,
+            "W" | "warfare" => Area::Warfare,
+            "Y" | "mythological" => Area::Mythological,
+            "X" | "all" => Area::AllOrNone,
             _ => Area::Unknown,
         }
     }
@@ -613,6 +686,12 @@ pub enum Geography {
     Unknown,
 }
 
+impl PartialEq for Geography {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 impl Geography {
     pub fn as_str(&self) -> &'static str {
         match &self {
@@ -818,3 +897,42 @@ impl Source {
         }
     }
 }
+
+/**
+ * How an english word is changed from its headword, ex: ran is the past of run
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum EnglishFormKind {
+    Plural,
+    Present,
+    ThirdPerson,
+    Past,
+    PastParticiple,
+    PresentParticiple,
+    Comparative,
+    Superlative,
+    Adverb,
+}
+
+impl PartialEq for EnglishFormKind {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl EnglishFormKind {
+    pub fn as_str(&self) -> &'static str {
+        match &self {
+            EnglishFormKind::Plural => "plural",
+            EnglishFormKind::Present => "present",
+            EnglishFormKind::ThirdPerson => "third person",
+            EnglishFormKind::Past => "past",
+            EnglishFormKind::PastParticiple => "past participle",
+            EnglishFormKind::PresentParticiple => "present participle",
+            EnglishFormKind::Comparative => "comparative",
+            EnglishFormKind::Superlative => "superlative",
+            EnglishFormKind::Adverb => "adverb",
+        }
+    }
+}
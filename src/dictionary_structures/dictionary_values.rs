@@ -1,13 +1,13 @@
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::dictionary_structures::dictionary_keys::{
-    Age, Area, Comparison, Declension, Frequency, Gender, Geography, Mood, Noun, Number, Numeral,
-    PartOfSpeech, Pronoun, Source, Tense, Verb, Voice,
+    Age, Area, Comparison, Declension, EnglishFormKind, Frequency, Gender, Geography, Mood, Noun,
+    Number, Numeral, PartOfSpeech, Pronoun, Source, Tense, Verb, Voice,
 };
 use crate::translators::Structure;
 use crate::utils::number_with_ending;
 use crate::utils::principle_part_generator::{generate_principle_parts, Generator};
-use crate::utils::type_translator::translate_type;
+use crate::utils::type_translator::{is_greek_type, translate_type};
 // We know that the dictionary data, and that all the values are the types they should be, so we can unwrap without worry.
 
 pub enum Part {
@@ -53,16 +53,40 @@ impl LatinWordInfo {
         }
     }
 
+    /**
+     * Replaces the stems of the word with its principle parts, ex: hort, hort, zzz, hortat -> hortor, hortari, hortatus sum
+     * A word without a known pattern keeps its stems.
+     */
     pub fn generate_principle_parts(&mut self) {
+        if let Ok(new_parts) = self.principle_parts() {
+            self.orth = new_parts[0].to_string();
+            self.parts = new_parts;
+        }
+    }
+
+    /**
+     * The first principle part of a word, ex: duco
+     */
+    pub fn lemma(&self) -> String {
+        self.principle_parts()
+            .ok()
+            .and_then(|parts| parts.first().cloned())
+            .unwrap_or_else(|| self.orth.to_string())
+    }
+
+    pub fn principle_parts(&self) -> Result<Vec<String>, String> {
         let parts = &self.parts;
 
         let n = match &self.n {
             Some(n) => n,
-            None => return,
+            None => return Err(format!("{} has no declension or conjugation", self.orth)),
         };
 
         if parts.is_empty() || n.len() < 2 {
-            return;
+            return Err(format!(
+                "{} has no stems to generate principle parts from",
+                self.orth
+            ));
         }
 
         let n_value_1 = match &self.n {
@@ -88,7 +112,7 @@ impl LatinWordInfo {
                         }
                     }
                 };
-                let new_parts = generate_principle_parts(
+                generate_principle_parts(
                     Generator::Noun,
                     n_value_1,
                     n_value_2,
@@ -97,9 +121,7 @@ impl LatinWordInfo {
                     None,
                     None,
                     None,
-                );
-                self.orth = new_parts[0].to_string();
-                self.parts = new_parts;
+                )
             }
             PartOfSpeech::Verb => {
                 let verb_type = match &self.form {
@@ -113,7 +135,7 @@ impl LatinWordInfo {
                         }
                     }
                 };
-                let new_parts = generate_principle_parts(
+                generate_principle_parts(
                     Generator::Verb,
                     n_value_1,
                     n_value_2,
@@ -122,9 +144,7 @@ impl LatinWordInfo {
                     None,
                     Some(verb_type),
                     None,
-                );
-                self.orth = new_parts[0].to_string();
-                self.parts = new_parts;
+                )
             }
             PartOfSpeech::Adjective => {
                 let comparison = match &self.form {
@@ -138,7 +158,7 @@ impl LatinWordInfo {
                         }
                     }
                 };
-                let new_parts = generate_principle_parts(
+                generate_principle_parts(
                     Generator::Adjective,
                     n_value_1,
                     n_value_2,
@@ -147,24 +167,18 @@ impl LatinWordInfo {
                     Some(comparison),
                     None,
                     None,
-                );
-                self.orth = new_parts[0].to_string();
-                self.parts = new_parts;
-            }
-            PartOfSpeech::Pronoun => {
-                let new_parts = generate_principle_parts(
-                    Generator::Pronoun,
-                    n_value_1,
-                    n_value_2,
-                    parts.to_vec(),
-                    None,
-                    None,
-                    None,
-                    None,
-                );
-                self.orth = new_parts[0].to_string();
-                self.parts = new_parts;
+                )
             }
+            PartOfSpeech::Pronoun => generate_principle_parts(
+                Generator::Pronoun,
+                n_value_1,
+                n_value_2,
+                parts.to_vec(),
+                None,
+                None,
+                None,
+                None,
+            ),
             PartOfSpeech::Numeral => {
                 let numeral_type = match &self.form {
                     Form::LongForm(form) => form.numeral.unwrap_or(Numeral::Unknown),
@@ -177,7 +191,7 @@ impl LatinWordInfo {
                         }
                     }
                 };
-                let new_parts = generate_principle_parts(
+                generate_principle_parts(
                     Generator::Numeral,
                     n_value_1,
                     n_value_2,
@@ -186,11 +200,9 @@ impl LatinWordInfo {
                     None,
                     None,
                     Some(numeral_type),
-                );
-                self.orth = new_parts[0].to_string();
-                self.parts = new_parts;
+                )
             }
-            _ => {}
+            _ => Err(format!("{} has no principle parts", self.pos.as_str())),
         }
     }
 
@@ -416,6 +428,7 @@ pub struct UniqueLatinWordInfo {
     pub form: Form,
     pub n: Option<Vec<NValue>>,
     pub info: WordInfo,
+    pub id: i32,
 }
 
 impl<'de> Deserialize<'de> for UniqueLatinWordInfo {
@@ -457,6 +470,7 @@ impl<'de> Deserialize<'de> for UniqueLatinWordInfo {
             }
             None => None,
         };
+        let id = map.remove("id").unwrap().to_string().parse().unwrap();
 
         Ok(UniqueLatinWordInfo {
             orth,
@@ -465,6 +479,7 @@ impl<'de> Deserialize<'de> for UniqueLatinWordInfo {
             form: Form::StrForm(form),
             n,
             info: serde_json::from_str(&info).unwrap(),
+            id,
         })
     }
 }
@@ -479,6 +494,7 @@ pub struct EnglishWordInfo {
     pub frequency: i16,
     pub compound: i16,
     pub semi: i16,
+    pub id: i32,
 }
 
 impl EnglishWordInfo {
@@ -492,6 +508,7 @@ impl EnglishWordInfo {
             frequency: 0,
             compound: 0,
             semi: 0,
+            id: 0,
         }
     }
 
@@ -504,6 +521,7 @@ impl EnglishWordInfo {
         self.frequency = english_word_info.frequency;
         self.compound = english_word_info.compound;
         self.semi = english_word_info.semi;
+        self.id = english_word_info.id;
     }
 
     pub fn set_orth(&mut self, orth: &str) {
@@ -537,6 +555,10 @@ impl EnglishWordInfo {
     pub fn set_semi(&mut self, semi: i16) {
         self.semi = semi;
     }
+
+    pub fn set_id(&mut self, id: i32) {
+        self.id = id;
+    }
 }
 
 impl Serialize for EnglishWordInfo {
@@ -577,6 +599,10 @@ impl Serialize for EnglishWordInfo {
             "semi".to_string(),
             serde_json::Value::Number(serde_json::Number::from(self.semi)),
         );
+        map.insert(
+            "id".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.id)),
+        );
         serde_json::Value::Object(map).serialize(serializer)
     }
 }
@@ -610,6 +636,8 @@ impl<'de> Deserialize<'de> for EnglishWordInfo {
             frequency,
             compound,
             semi,
+            // the english words are numbered by their latin entry when they are loaded
+            id: 0,
         })
     }
 }
@@ -718,6 +746,10 @@ impl<'de> Deserialize<'de> for WordInfo {
     }
 }
 
+/**
+ * An ending and the form it gives a word.
+ * Some endings only go on one of the stems of a word, ex: -ior on the 3rd stem (comparative) of an adjective
+ */
 #[derive(Debug, Clone)]
 pub struct Inflection {
     pub ending: String,
@@ -725,6 +757,8 @@ pub struct Inflection {
     pub note: Option<String>,
     pub n: Option<Vec<NValue>>,
     pub form: Form,
+    pub stem_key: Option<i8>,
+    pub id: i32,
 }
 
 impl Inflection {
@@ -735,6 +769,8 @@ impl Inflection {
             note: None,
             n: None,
             form: Form::StrForm(String::new()),
+            stem_key: None,
+            id: 0,
         }
     }
 
@@ -742,6 +778,10 @@ impl Inflection {
         self.ending = ending.to_string();
     }
 
+    pub fn set_stem_key(&mut self, stem_key: i8) {
+        self.stem_key = Some(stem_key);
+    }
+
     pub fn set_pos(&mut self, pos: PartOfSpeech) {
         self.pos = pos;
     }
@@ -757,6 +797,10 @@ impl Inflection {
     pub fn set_form(&mut self, form: Form) {
         self.form = form;
     }
+
+    pub fn set_id(&mut self, id: i32) {
+        self.id = id;
+    }
 }
 
 impl<'de> Deserialize<'de> for Inflection {
@@ -798,13 +842,30 @@ impl<'de> Deserialize<'de> for Inflection {
         };
         let form = serde_json::from_value(map.remove("form").unwrap())
             .expect("Failed to deserialize form");
+        let stem_key = match map.remove("stem_key") {
+            Some(stem_key) => serde_json::from_value(stem_key).unwrap_or(None),
+            None => None,
+        };
+
+        let ending = ending.trim_matches('"');
+        // some endings keep the stem they go on and their length, ex: "3 2 or" (melior), "1 0" (miser)
+        let (ending, stem_key) = match ending.splitn(3, ' ').collect::<Vec<&str>>()[..] {
+            [key, length, ending] if length.parse::<usize>().is_ok() => {
+                (ending, key.parse::<i8>().ok())
+            }
+            [key, "0"] => ("", key.parse::<i8>().ok()),
+            _ => (ending, stem_key),
+        };
+        let id = map.remove("id").unwrap().to_string().parse().unwrap();
 
         Ok(Inflection {
-            ending: ending.to_string().trim_matches('"').to_string(),
+            ending: ending.to_string(),
             pos: PartOfSpeech::dict_key_to_part_of_speech(&pos),
             note,
             n,
             form: Form::StrForm(form),
+            stem_key,
+            id,
         })
     }
 }
@@ -852,6 +913,17 @@ impl Serialize for Inflection {
                 ),
             },
         );
+        map.insert(
+            "stem_key".to_string(),
+            match self.stem_key {
+                Some(stem_key) => serde_json::Value::Number(stem_key.into()),
+                None => serde_json::Value::Null,
+            },
+        );
+        map.insert(
+            "id".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.id)),
+        );
         serde_json::Value::Object(map).serialize(serializer)
     }
 }
@@ -863,6 +935,7 @@ pub struct Stem {
     pub orth: String,
     pub n: Option<Vec<NValue>>,
     pub wid: i32,
+    pub id: i32,
 }
 
 impl Stem {
@@ -873,6 +946,7 @@ impl Stem {
             orth: String::new(),
             n: None,
             wid: 0,
+            id: 0,
         }
     }
 
@@ -895,6 +969,10 @@ impl Stem {
     pub fn set_wid(&mut self, wid: i32) {
         self.wid = wid;
     }
+
+    pub fn set_id(&mut self, id: i32) {
+        self.id = id;
+    }
 }
 
 impl Serialize for Stem {
@@ -937,6 +1015,10 @@ impl Serialize for Stem {
             "wid".to_string(),
             serde_json::Value::Number(serde_json::Number::from(self.wid)),
         );
+        map.insert(
+            "id".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.id)),
+        );
         serde_json::Value::Object(map).serialize(serializer)
     }
 }
@@ -987,6 +1069,8 @@ impl<'de> Deserialize<'de> for Stem {
             orth: orth.to_string().trim_matches('"').to_string(),
             n,
             wid,
+            // the stems are numbered by their latin entry when they are loaded
+            id: 0,
         })
     }
 }
@@ -998,6 +1082,7 @@ pub enum ModifierType {
     Tackon,
     Packon,
     NotPackon,
+    Tickon,
     Unspecified,
 }
 
@@ -1009,6 +1094,7 @@ impl ModifierType {
             ModifierType::Tackon => "enclitic tackon".to_string(),
             ModifierType::Packon => "enclitic packon".to_string(),
             ModifierType::NotPackon => "enclitic not packon".to_string(),
+            ModifierType::Tickon => "tickon".to_string(),
             ModifierType::Unspecified => "unspecified".to_string(),
         }
     }
@@ -1021,6 +1107,7 @@ pub struct Modifier {
     pub senses: Vec<String>,
     pub orth: String,
     pub modifier: ModifierType,
+    pub id: i32,
 }
 
 impl Modifier {
@@ -1031,6 +1118,7 @@ impl Modifier {
             senses: Vec::new(),
             orth: String::new(),
             modifier: ModifierType::Unspecified,
+            id: 0,
         }
     }
 
@@ -1053,6 +1141,10 @@ impl Modifier {
     pub fn set_modifier(&mut self, modifier: ModifierType) {
         self.modifier = modifier;
     }
+
+    pub fn set_id(&mut self, id: i32) {
+        self.id = id;
+    }
 }
 
 impl<'de> Deserialize<'de> for Modifier {
@@ -1071,6 +1163,7 @@ impl<'de> Deserialize<'de> for Modifier {
             .expect("Failed to deserialize senses");
         let orth: String = serde_json::from_value(map.remove("orth").unwrap())
             .expect("Failed to deserialize orth");
+        let id = map.remove("id").unwrap().to_string().parse().unwrap();
 
         Ok(Modifier {
             pos: PartOfSpeech::dict_key_to_part_of_speech(&pos),
@@ -1078,6 +1171,7 @@ impl<'de> Deserialize<'de> for Modifier {
             senses,
             orth: orth.to_string().trim_matches('"').to_string(),
             modifier: ModifierType::Unspecified,
+            id,
         })
     }
 }
@@ -1125,6 +1219,10 @@ impl Serialize for Modifier {
             "modifier".to_string(),
             serde_json::Value::String(self.modifier.as_str()),
         );
+        map.insert(
+            "id".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.id)),
+        );
         serde_json::Value::Object(map).serialize(serializer)
     }
 }
@@ -1294,6 +1392,7 @@ impl Form {
                     PartOfSpeech::Noun => {
                         long_form.gender = Some(Gender::dict_key_to_gender(&word_type));
                         long_form.noun = Some(Noun::dict_key_to_noun(form_array[3]));
+                        long_form.greek = Some(is_greek_type(&n_value, part_of_speech));
                         long_form.declension_type = Some(translate_type(n_value, part_of_speech));
                         *self = Form::LongForm(long_form);
                     }
@@ -1307,6 +1406,7 @@ impl Form {
                         *self = Form::LongForm(long_form);
                     }
                     PartOfSpeech::Adjective => {
+                        long_form.greek = Some(is_greek_type(&n_value, part_of_speech));
                         long_form.declension_type = Some(translate_type(n_value, part_of_speech));
                         *self = Form::LongForm(long_form);
                     }
@@ -1351,6 +1451,7 @@ pub struct LongForm {
     pub declension: Option<Declension>,
     pub declension_type: Option<String>,
     pub gender: Option<Gender>,
+    pub greek: Option<bool>,
     pub mood: Option<Mood>,
     pub noun: Option<Noun>,
     pub number: Option<Number>,
@@ -1400,6 +1501,10 @@ impl Serialize for LongForm {
             "gender".to_string(),
             serde_json::Value::String(self.gender.unwrap_or(Gender::Unknown).as_str().to_string()),
         );
+        map.insert(
+            "greek".to_string(),
+            serde_json::Value::Bool(self.greek.unwrap_or(false)),
+        );
         map.insert(
             "mood".to_string(),
             serde_json::Value::String(self.mood.unwrap_or(Mood::Unknown).as_str().to_string()),
@@ -1471,6 +1576,7 @@ impl LongForm {
             declension: None,
             declension_type: None,
             gender: None,
+            greek: None,
             mood: None,
             noun: None,
             number: None,
@@ -1487,7 +1593,7 @@ impl LongForm {
 
     pub fn as_str(&self) -> String {
         format!(
-            "{} {} {} {} {} {} {} {} {} {} {} {} {} {} {}",
+            "{} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {}",
             self.comparison.unwrap_or(Comparison::Unknown).as_str(),
             self.declension.unwrap_or(Declension::Unknown).as_str(),
             self.declension_type
@@ -1507,6 +1613,12 @@ impl LongForm {
             self.verb.unwrap_or(Verb::Unknown).as_str(),
             self.verb_type.to_owned().unwrap_or("unknown".to_string()),
             self.voice.unwrap_or(Voice::Unknown).as_str(),
+            // the declension type of a word already names a greek declension
+            if self.greek.unwrap_or(false) && self.declension_type.is_none() {
+                "greek"
+            } else {
+                "unknown"
+            },
         )
     }
 
@@ -1534,6 +1646,11 @@ impl LongForm {
         self
     }
 
+    pub fn set_greek(mut self, greek: bool) -> LongForm {
+        self.greek = Some(greek);
+        self
+    }
+
     pub fn set_mood(mut self, mood: Mood) -> LongForm {
         self.mood = Some(mood);
         self
@@ -1580,19 +1697,27 @@ impl LongForm {
     }
 }
 
+/**
+ * A word part that attaches to whole words.
+ * The form is what it attaches to, ex: "1 0 INDEF" for the packon -dam (quidam)
+ */
 #[derive(Clone, Debug)]
 pub struct Attachment {
     pub pos: PartOfSpeech,
+    pub form: String,
     pub senses: Vec<String>,
     pub orth: String,
+    pub id: i32,
 }
 
 impl Attachment {
     pub fn new() -> Attachment {
         Attachment {
             pos: PartOfSpeech::Unknown,
+            form: String::new(),
             senses: Vec::new(),
             orth: String::new(),
+            id: 0,
         }
     }
 
@@ -1607,6 +1732,10 @@ impl Attachment {
     pub fn set_orth(&mut self, orth: &str) {
         self.orth = orth.to_string();
     }
+
+    pub fn set_id(&mut self, id: i32) {
+        self.id = id;
+    }
 }
 
 impl Serialize for Attachment {
@@ -1619,6 +1748,10 @@ impl Serialize for Attachment {
             "pos".to_string(),
             serde_json::Value::String(self.pos.as_str().to_string()),
         );
+        map.insert(
+            "form".to_string(),
+            serde_json::Value::String(self.form.to_string()),
+        );
         map.insert(
             "senses".to_string(),
             serde_json::Value::Array(
@@ -1632,6 +1765,10 @@ impl Serialize for Attachment {
             "orth".to_string(),
             serde_json::Value::String(self.orth.to_string()),
         );
+        map.insert(
+            "id".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.id)),
+        );
         serde_json::Value::Object(map).serialize(serializer)
     }
 }
@@ -1649,10 +1786,46 @@ impl<'de> Deserialize<'de> for Attachment {
         let orth = serde_json::from_value(map.remove("orth").unwrap())
             .expect("Failed to deserialize orth");
 
+        // the dictionary keeps the form with the part of speech, ex: "PACK    1  0 INDEF"
+        let mut pos_array = pos.split_whitespace();
+        let part_of_speech = pos_array.next().unwrap_or_default();
+        let form = match map.remove("form") {
+            Some(form) => serde_json::from_value(form).expect("Failed to deserialize form"),
+            None => pos_array.collect::<Vec<&str>>().join(" "),
+        };
+        let id = map.remove("id").unwrap().to_string().parse().unwrap();
+
         Ok(Attachment {
-            pos: PartOfSpeech::dict_key_to_part_of_speech(&pos),
+            pos: PartOfSpeech::dict_key_to_part_of_speech(part_of_speech),
+            form,
             senses,
             orth,
+            id,
         })
     }
 }
+
+/**
+ * An abbreviation and the latin it stands for.
+ * The orth is written with a space between each abbreviated part, ex: "d m s" for D·M·S
+ * Epigraphic abbreviations are only expanded when reading inscriptions, ex: "v s l m" (votum solvit libens merito)
+ */
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Abbreviation {
+    pub orth: String,
+    pub expansion: String,
+    pub senses: Vec<String>,
+    #[serde(default)]
+    pub epigraphic: bool,
+}
+
+/**
+ * An english word that does not follow the suffix rules, and the headword it comes from.
+ * ex: ran -> run (past), children -> child (plural), better -> good (comparative)
+ */
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EnglishIrregularForm {
+    pub form: String,
+    pub lemma: String,
+    pub kind: EnglishFormKind,
+}
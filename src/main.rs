@@ -1,5 +1,7 @@
 pub mod cli;
 pub mod dictionary_structures;
+pub mod paradigms;
+pub mod search;
 pub mod translators;
 pub mod use_data;
 pub mod utils;
@@ -7,16 +9,34 @@ pub mod utils;
 use std::io::Write;
 
 use cli::{Arg, Cli, Command};
-use dictionary_structures::dictionary_keys::PartOfSpeech;
+use dictionary_structures::dictionary_keys::{Age, Area, Number, PartOfSpeech};
+use paradigms::agreement::{agree, parse_gender};
+use paradigms::comparison::{find_comparisons, WordComparisons};
+use paradigms::pronouns::{all_pronoun_paradigms, find_pronoun_paradigms, PronounParadigm};
+use paradigms::synopsis::find_synopses;
+use search::family::find_family;
+use search::synonyms::find_synonyms;
+use search::{SenseIndex, WordFilter};
+use translators::english_to_latin::morphology::inflect_english;
+use translators::english_to_latin::phrases::find_longest_phrase;
 use translators::english_to_latin::translate_english_to_latin;
+use translators::latin_to_english::abbreviations::{find_abbreviation, load_abbreviations};
+use translators::latin_to_english::inscription::read_inscription;
+use translators::latin_to_english::principle_parts::{find_principle_parts, WordPrincipleParts};
+use translators::latin_to_english::trace::Trace;
 use translators::latin_to_english::translate_latin_to_english;
+use translators::latin_to_english::tricks::contractions::split_elisions;
 use translators::{DisplayType, Language, Translation, TranslationType};
-use use_data::{get_list, WordType};
+use use_data::lookup::find_by_id;
+use use_data::pattern::{WordPattern, WordPatterns};
+use use_data::sampler::Sampler;
+use use_data::where_clause::WhereClause;
+use use_data::{get_list, ListOptions, WordType};
 use utils::data::{get_english_dictionary, get_latin_dictionary};
 use utils::sanitize_word;
+use utils::sorting::{parse_sort_by, SortBy, SortKey};
 
 use crate::cli::ArgValue;
-//TODO: add a command for searching a word by id in english or latin dictionary
 //TODO: display the amount of time it took for a command to execute
 fn main() {
     let global_args_for_translation = vec![
@@ -36,6 +56,12 @@ fn main() {
             .with_short('s')
             .with_long("sort")
             .with_help("Sort the output by word frequency"),
+        Arg::new()
+            .with_name("sort_by")
+            .with_short('b')
+            .with_long("sort-by")
+            .with_value_name("KEYS")
+            .with_help("Sort the output by keys separated by commas, each can end with :asc or :desc. Keys: score, frequency, age, pos, lemma, forms, id"),
         Arg::new()
             .with_name("pretty")
             .with_short('p')
@@ -60,13 +86,27 @@ fn main() {
                     .with_short('t')
                     .with_long("tricks")
                     .with_help("Will attempt to use various tricks to find the translation"),
+            )
+            .with_arg(
+                Arg::new()
+                    .with_name("explain")
+                    .with_short('e')
+                    .with_long("explain")
+                    .with_help("Shows each step taken to analyze the word"),
+            )
+            .with_arg(
+                Arg::new()
+                    .with_name("inscription")
+                    .with_short('i')
+                    .with_long("inscription")
+                    .with_help("Reads the text as an inscription: normalizes V/U and I/J, expands abbreviations and splits unspaced text into words"),
             ),
         Command::new("getList", "Gets a list of words based on the options provided")
             .with_arg(
                 Arg::new()
                 .with_name("type")
                 .with_value_name("TYPE")
-                .with_help("The type of words to get. Options: english, latin, inflections, not_packons, packons, prefixes, stems, suffixes, tackons, tickons, unique_latin"),
+                .with_help("The type of words to get. Options: english, homographs, latin, inflections, not_packons, packons, prefixes, stems, suffixes, tackons, tickons, unique_latin"),
             )
             .with_arg(
                 Arg::new()
@@ -113,8 +153,31 @@ fn main() {
                 .with_name("random")
                 .with_short('r')
                 .with_long("random")
-                .with_help("Get words from a random position")
-                .requires("amount"),
+                .with_help("Get words in a random order, without repeats"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("offset")
+                .with_short('o')
+                .with_long("offset")
+                .with_value_name("OFFSET")
+                .with_help("The amount of words to skip, ex: -o 20 -a 10 gets the 3rd page of 10"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("seed")
+                .with_short('s')
+                .with_long("seed")
+                .with_value_name("SEED")
+                .with_help("A number that makes the random order the same every time")
+                .requires("random"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("count")
+                .with_short('c')
+                .with_long("count")
+                .with_help("Prints how many words matched and which of them are in the list, ex: 11-20 of 154 entries"),
             )
             .with_arg(
                 Arg::new()
@@ -130,6 +193,283 @@ fn main() {
                 .with_long("to")
                 .with_value_name("TO")
                 .with_help("The file to export the results to"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("glob")
+                .with_short('g')
+                .with_long("glob")
+                .with_value_name("PATTERN")
+                .with_help("Latin words that match a pattern, ? is any letter and * any letters, ex: a?o, *tio"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("regex")
+                .with_short('x')
+                .with_long("regex")
+                .with_value_name("REGEX")
+                .with_help("Latin words that match a regular expression, ex: ^(re|de).*are$"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("anagram")
+                .with_short('l')
+                .with_long("anagram")
+                .with_value_name("LETTERS")
+                .with_help("Latin words made of exactly these letters, ? is any letter, ex: roma"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("forms")
+                .with_short('f')
+                .with_long("forms")
+                .with_help("Matches the patterns against every form of a word, not only the principle parts"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("sort_by")
+                .with_short('b')
+                .with_long("sort-by")
+                .with_value_name("KEYS")
+                .with_help("Sort the words by keys separated by commas, each can end with :asc or :desc. Keys: frequency, age, pos, lemma, id"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("where")
+                .with_short('w')
+                .with_long("where")
+                .with_value_name("CONDITIONS")
+                .with_help("Conditions joined by and/or, ex: \"freq>=B and pos=verb and conj=3\". Fields: age, area, geo, freq, source, pos, decl, conj, gender, noun, verb"),
+            ),
+        Command::new("inflect", "Translates an english phrase to the latin form that says the same thing")
+            .with_arg(
+                Arg::new()
+                .with_name("phrase")
+                .with_value_name("PHRASE")
+                .with_help("A word with its subject, auxiliaries or preposition, ex: they will love, of the farmers, having been carried"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("parts", "Gets the principle parts of latin words")
+            .with_arg(
+                Arg::new()
+                .with_name("words")
+                .with_value_name("WORDS")
+                .with_help("The words to get the principle parts of, in any form"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("tricks")
+                .with_short('t')
+                .with_long("tricks")
+                .with_help("Will attempt to use various tricks to find the word"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("compare", "Gets the positive, comparative and superlative of adjectives and their adverbs")
+            .with_arg(
+                Arg::new()
+                .with_name("words")
+                .with_value_name("WORDS")
+                .with_help("The adjectives or adverbs to compare, in any form"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("tricks")
+                .with_short('t')
+                .with_long("tricks")
+                .with_help("Will attempt to use various tricks to find the word"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("synopsis", "Conjugates a verb in every tense, mood and voice for one person and number")
+            .with_arg(
+                Arg::new()
+                .with_name("verb")
+                .with_value_name("VERB PERSON NUMBER")
+                .with_help("The verb in any form, the person (1, 2, 3) and the number (singular, plural), ex: amo 3 singular"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("existing")
+                .with_short('e')
+                .with_long("existing")
+                .with_help("Leaves out the forms that deponent and defective verbs lack"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("tricks")
+                .with_short('t')
+                .with_long("tricks")
+                .with_help("Will attempt to use various tricks to find the word"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("pronoun", "Declines pronouns in every case, number and gender")
+            .with_arg(
+                Arg::new()
+                .with_name("words")
+                .with_value_name("WORDS")
+                .with_help("The pronouns to decline, ex: is, qui, quidam, ego (every pronoun if none are given)"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("agree", "Declines a noun together with the adjectives that agree with it")
+            .with_arg(
+                Arg::new()
+                .with_name("phrase")
+                .with_value_name("NOUN ADJECTIVES")
+                .with_help("The noun followed by its adjectives, in any form that agrees, ex: vir bonus"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("gender")
+                .with_short('g')
+                .with_long("gender")
+                .with_value_name("GENDER")
+                .with_help("The gender of a noun that can be more than one, ex: masculine, feminine, neuter"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("tricks")
+                .with_short('t')
+                .with_long("tricks")
+                .with_help("Will attempt to use various tricks to find the word"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("search", "Searches the senses of latin words")
+            .with_arg(
+                Arg::new()
+                .with_name("query")
+                .with_value_name("QUERY")
+                .with_help("The words to find, supports AND, OR, NOT, \"quoted phrases\", prefix* and (groups)"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pos")
+                .with_short('o')
+                .with_long("pos")
+                .with_value_name("POS")
+                .with_help("The part of speeches to include, separated by commas"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("age")
+                .with_short('a')
+                .with_long("age")
+                .with_value_name("AGE")
+                .with_help("The ages to include, separated by commas, ex: classical, late"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("area")
+                .with_short('r')
+                .with_long("area")
+                .with_value_name("AREA")
+                .with_help("The areas to include, separated by commas, ex: warfare, legal"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("max")
+                .with_short('m')
+                .with_long("max")
+                .with_value_name("MAX")
+                .with_help("The maximum number of results (default: 10)"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("family", "Finds the words that share a root with a latin word")
+            .with_arg(
+                Arg::new()
+                .with_name("word")
+                .with_value_name("WORD")
+                .with_help("The word to find the relatives of, in any form, ex: duco"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("tricks")
+                .with_short('t')
+                .with_long("tricks")
+                .with_help("Will attempt to use various tricks to find the word"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("synonyms", "Finds the latin words that share english senses with a latin word")
+            .with_arg(
+                Arg::new()
+                .with_name("word")
+                .with_value_name("WORD")
+                .with_help("The word to find the synonyms of, in any form, ex: amo"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("max")
+                .with_short('m')
+                .with_long("max")
+                .with_value_name("MAX")
+                .with_help("The maximum number of synonyms per definition (default: 10)"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("tricks")
+                .with_short('t')
+                .with_long("tricks")
+                .with_help("Will attempt to use various tricks to find the word"),
+            )
+            .with_arg(
+                Arg::new()
+                .with_name("pretty")
+                .with_short('p')
+                .with_long("pretty")
+                .with_help("Prints the output in a pretty format"),
+            ),
+        Command::new("show", "Shows an entry of the data by its id, with the entries it refers to")
+            .with_arg(
+                Arg::new()
+                .with_name("entry")
+                .with_value_name("TYPE ID")
+                .with_help("The type of the entry and its id, ex: latin 2871. Types are the ones of getList"),
             ),
         Command::new("help", "Helps you")
             .with_arg(
@@ -151,11 +491,11 @@ fn main() {
                 .throw_if_none()
                 .parse::<usize>()
                 .unwrap();
-            let sort = command.has("sort");
+            let sort_by = sort_keys(command, SortKey::Score);
             let pretty = command.has("pretty");
             let detailed = command.has("detailed");
 
-            english_to_latin(&words, max, sort, pretty, detailed);
+            english_to_latin(&words, max, &sort_by, pretty, detailed);
         }
         "transLat" => {
             let words = command.get_value().throw_if_none();
@@ -164,12 +504,17 @@ fn main() {
                 .throw_if_none()
                 .parse::<usize>()
                 .unwrap();
-            let sort = command.has("sort");
+            let sort_by = sort_keys(command, SortKey::Frequency);
             let pretty = command.has("pretty");
             let detailed = command.has("detailed");
             let tricks = command.has("tricks");
+            let explain = command.has("explain");
 
-            latin_to_english(&words, max, tricks, sort, pretty, detailed);
+            if command.has("inscription") {
+                inscription_to_english(&words, max, tricks, &sort_by, pretty, detailed);
+            } else {
+                latin_to_english(&words, max, tricks, &sort_by, pretty, detailed, explain);
+            }
         }
         "getList" => {
             let type_of_words = command.get_value().throw_if_none();
@@ -179,8 +524,23 @@ fn main() {
             let exact = command.get_value_of("exact");
             let amount = command.get_value_of("amount");
             let random = command.has("random");
+            let offset = command.get_value_of("offset");
+            let seed = command.get_value_of("seed");
+            let count = command.has("count");
             let display = command.has("display");
             let to = command.get_value_of("to");
+            let glob = command.get_value_of("glob").to_option();
+            let regex = command.get_value_of("regex").to_option();
+            let anagram = command.get_value_of("anagram").to_option();
+            let forms = command.has("forms");
+            let sort_by = sort_keys(command, SortKey::Frequency);
+            let where_clause = match command.get_value_of("where").to_option() {
+                Some(clause) => WhereClause::parse(&clause).unwrap_or_else(|e| {
+                    println!("{}", e);
+                    std::process::exit(0);
+                }),
+                None => WhereClause::new(),
+            };
 
             if !WordType::is_valid_word_type(&type_of_words) {
                 println!(
@@ -194,22 +554,7 @@ fn main() {
                 std::process::exit(0);
             });
 
-            let pos_list = match pos {
-                ArgValue::Present(pos) => {
-                    let pos_list: Vec<PartOfSpeech> = pos
-                        .split(",")
-                        .map(|pos| PartOfSpeech::dict_key_to_part_of_speech(pos.trim()))
-                        .collect();
-                    Some(pos_list)
-                }
-                ArgValue::Missing(_) => None,
-            };
-
-            if pos_list.is_some() && pos_list.as_ref().unwrap().contains(&PartOfSpeech::Unknown) {
-                println!("Invalid part of speech entered.");
-                println!("Please use the following: noun, verb, participle, adjective, preposition, pronoun, interjection, numeral, conjunction, adverb, number, supine, packon, tackon, prefix, suffix");
-                std::process::exit(0);
-            }
+            let pos_list = parse_pos_list(pos);
 
             let max = match max {
                 ArgValue::Present(max) => Some(max.parse::<usize>().unwrap() as i32),
@@ -227,18 +572,176 @@ fn main() {
             };
 
             let amount = match amount {
-                ArgValue::Present(amount) => Some(amount.parse::<usize>().unwrap() as i32),
+                ArgValue::Present(amount) => Some(amount.parse::<usize>().unwrap_or_else(|_| {
+                    println!("Amount must be a number, but {} was provided", amount);
+                    std::process::exit(0);
+                })),
+                ArgValue::Missing(_) => None,
+            };
+
+            let offset = match offset {
+                ArgValue::Present(offset) => offset.parse::<usize>().unwrap_or_else(|_| {
+                    println!("Offset must be a number, but {} was provided", offset);
+                    std::process::exit(0);
+                }),
+                ArgValue::Missing(_) => 0,
+            };
+
+            let seed = match seed {
+                ArgValue::Present(seed) => Some(seed.parse::<u64>().unwrap_or_else(|_| {
+                    println!("Seed must be a number, but {} was provided", seed);
+                    std::process::exit(0);
+                })),
                 ArgValue::Missing(_) => None,
             };
 
+            let sampler = Sampler::new()
+                .set_amount(amount)
+                .set_offset(offset)
+                .set_random(random)
+                .set_seed(seed)
+                .set_count(count);
+
             let to = match to {
                 ArgValue::Present(to) => Some(to),
                 ArgValue::Missing(_) => None,
             };
 
-            get_list(
-                word_type, pos_list, max, min, exact, amount, random, display, to,
-            );
+            let mut patterns = WordPatterns::new().set_forms(forms);
+            if let Some(glob) = glob {
+                patterns = patterns.add_pattern(WordPattern::glob(&glob).unwrap_or_else(|e| {
+                    println!("{}", e);
+                    std::process::exit(0);
+                }));
+            }
+            if let Some(regex) = regex {
+                patterns = patterns.add_pattern(WordPattern::regex(&regex).unwrap_or_else(|e| {
+                    println!("{}", e);
+                    std::process::exit(0);
+                }));
+            }
+            if let Some(anagram) = anagram {
+                patterns = patterns.add_pattern(WordPattern::anagram(&anagram));
+            }
+
+            let options = ListOptions::new()
+                .set_pos_list(pos_list)
+                .set_lengths(max, min, exact)
+                .set_patterns(patterns)
+                .set_where_clause(where_clause)
+                .set_sort_by(sort_by)
+                .set_sampler(sampler);
+
+            get_list(word_type, options, display, to);
+        }
+        "inflect" => {
+            let phrase = command.get_value().throw_if_none();
+            let pretty = command.has("pretty");
+
+            english_inflection(&phrase, pretty);
+        }
+        "parts" => {
+            let words = command.get_value().throw_if_none();
+            let tricks = command.has("tricks");
+            let pretty = command.has("pretty");
+
+            principle_parts(&words, tricks, pretty);
+        }
+        "compare" => {
+            let words = command.get_value().throw_if_none();
+            let tricks = command.has("tricks");
+            let pretty = command.has("pretty");
+
+            comparisons(&words, tricks, pretty);
+        }
+        "synopsis" => {
+            let value = command.get_value().throw_if_none();
+            let existing = command.has("existing");
+            let tricks = command.has("tricks");
+            let pretty = command.has("pretty");
+
+            verb_synopsis(&value, existing, tricks, pretty);
+        }
+        "pronoun" => {
+            let words = command.get_value().to_option();
+            let pretty = command.has("pretty");
+
+            pronoun_paradigms(words, pretty);
+        }
+        "agree" => {
+            let phrase = command.get_value().throw_if_none();
+            let gender = command.get_value_of("gender").to_option();
+            let tricks = command.has("tricks");
+            let pretty = command.has("pretty");
+
+            agreement(&phrase, gender, tricks, pretty);
+        }
+        "search" => {
+            let query = command.get_value().throw_if_none();
+            let pos = command.get_value_of("pos");
+            let age = command.get_value_of("age").to_option();
+            let area = command.get_value_of("area").to_option();
+            let max = command.get_value_of("max");
+            let pretty = command.has("pretty");
+
+            let filter = WordFilter::new()
+                .set_pos(parse_pos_list(pos))
+                .set_age(age.map(|age| parse_ages(&age)))
+                .set_area(area.map(|area| parse_areas(&area)));
+
+            let max = match max {
+                ArgValue::Present(max) => max.parse::<usize>().unwrap_or_else(|_| {
+                    println!("Max must be a number, but {} was provided", max);
+                    std::process::exit(0);
+                }),
+                ArgValue::Missing(_) => 10,
+            };
+
+            sense_search(&query, &filter, max, pretty);
+        }
+        "family" => {
+            let word = command.get_value().throw_if_none();
+            let tricks = command.has("tricks");
+            let pretty = command.has("pretty");
+
+            word_family(&word, tricks, pretty);
+        }
+        "synonyms" => {
+            let word = command.get_value().throw_if_none();
+            let max = command.get_value_of("max");
+            let tricks = command.has("tricks");
+            let pretty = command.has("pretty");
+
+            let max = match max {
+                ArgValue::Present(max) => max.parse::<usize>().unwrap_or_else(|_| {
+                    println!("Max must be a number, but {} was provided", max);
+                    std::process::exit(0);
+                }),
+                ArgValue::Missing(_) => 10,
+            };
+
+            word_synonyms(&word, tricks, max, pretty);
+        }
+        "show" => {
+            let entry = command.get_value().throw_if_none();
+
+            let (type_of_words, id) = match entry.split_whitespace().collect::<Vec<&str>>()[..] {
+                [type_of_words, id] => (type_of_words, id),
+                _ => {
+                    println!("Please provide the type of the entry and its id, ex: latin 2871");
+                    std::process::exit(0);
+                }
+            };
+            let word_type = WordType::from_str(type_of_words).unwrap_or_else(|e| {
+                println!("{}", e);
+                std::process::exit(0);
+            });
+            let id = id.parse::<i32>().unwrap_or_else(|_| {
+                println!("Id must be a number, but {} was provided", id);
+                std::process::exit(0);
+            });
+
+            show_entry(&word_type, id);
         }
         "help" => {
             let command = command.get_value().to_option();
@@ -277,10 +780,18 @@ fn main() {
                     }
                     _ => match language {
                         Language::Latin => {
-                            latin_to_english(input, 6, true, true, true, false);
+                            latin_to_english(
+                                input,
+                                6,
+                                true,
+                                &[SortBy::new(SortKey::Frequency)],
+                                true,
+                                false,
+                                false,
+                            );
                         }
                         Language::English => {
-                            english_to_latin(input, 6, true, true, true);
+                            english_to_latin(input, 6, &[SortBy::new(SortKey::Score)], true, true);
                         }
                     },
                 }
@@ -297,21 +808,76 @@ fn latin_to_english(
     latin_text: &str,
     max: usize,
     tricks: bool,
-    sort: bool,
+    sort_by: &[SortBy],
     pretty_output: bool,
     detailed_pretty_output: bool,
+    explain: bool,
 ) {
     let latin_words: Vec<&str> = latin_text.split(" ").collect();
+    let abbreviations = load_abbreviations(false);
     let mut translations: Vec<Translation> = Vec::new();
 
-    for word in latin_words {
-        let mut definitions = translate_latin_to_english(&sanitize_word(word), tricks);
-        definitions.truncate(max);
-        let mut translation =
-            Translation::new(word.to_string(), TranslationType::Latin(definitions));
+    let mut index = 0;
+    while index < latin_words.len() {
+        // abbreviations are found before the word is sanitized, which removes their periods
+        if let Some((matches, length)) = find_abbreviation(&latin_words[index..], &abbreviations) {
+            let mut trace = Trace::new(explain);
+            let mut definitions = Vec::new();
 
-        translation.post_process(Language::Latin, sort);
-        translations.push(translation);
+            // the words keep their own analyses next to the expansion, ex: AD -> ad and ante diem
+            for word in &latin_words[index..index + length] {
+                definitions.extend(translate_latin_to_english(
+                    &sanitize_word(word),
+                    tricks,
+                    &mut trace,
+                ));
+            }
+            definitions.truncate(max);
+            let mut translation = Translation::new(
+                latin_words[index..index + length].join(" "),
+                TranslationType::Latin(definitions),
+            );
+            translation.set_abbreviations(matches);
+
+            if trace.is_enabled() {
+                translation.set_trace(trace);
+            }
+
+            translation.post_process(Language::Latin, sort_by);
+            translations.push(translation);
+            index += length;
+            continue;
+        }
+
+        // words joined by elision are translated separately, ex: atqu'ego -> atqu' + ego
+        for word in split_elisions(latin_words[index]) {
+            let mut trace = Trace::new(explain);
+            let mut definitions = Vec::new();
+            for candidate in &word.candidates {
+                let mut candidate_definitions =
+                    translate_latin_to_english(&sanitize_word(candidate), tricks, &mut trace);
+
+                if let Some(explanation) = &word.explanation {
+                    for definition in candidate_definitions.iter_mut() {
+                        let mut explanations = definition.tricks.take().unwrap_or_default();
+                        explanations.insert(0, explanation.to_string());
+                        definition.set_tricks(&explanations);
+                    }
+                }
+
+                definitions.extend(candidate_definitions);
+            }
+            definitions.truncate(max);
+            let mut translation = Translation::new(word.word, TranslationType::Latin(definitions));
+
+            if trace.is_enabled() {
+                translation.set_trace(trace);
+            }
+
+            translation.post_process(Language::Latin, sort_by);
+            translations.push(translation);
+        }
+        index += 1;
     }
 
     if pretty_output {
@@ -323,31 +889,344 @@ fn latin_to_english(
     }
 }
 
+fn inscription_to_english(
+    inscription: &str,
+    max: usize,
+    tricks: bool,
+    sort_by: &[SortBy],
+    pretty_output: bool,
+    detailed_pretty_output: bool,
+) {
+    let readings = read_inscription(inscription, tricks, max, sort_by);
+
+    if pretty_output {
+        for reading in readings {
+            reading.display(detailed_pretty_output);
+        }
+    } else {
+        println!("{}", serde_json::to_string_pretty(&readings).unwrap());
+    }
+}
+
+fn principle_parts(latin_text: &str, tricks: bool, pretty_output: bool) {
+    let principle_parts: Vec<WordPrincipleParts> = latin_text
+        .split(" ")
+        .map(|word| find_principle_parts(&sanitize_word(word), tricks))
+        .collect();
+
+    if pretty_output {
+        for word_principle_parts in principle_parts {
+            word_principle_parts.display();
+        }
+    } else {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&principle_parts).unwrap()
+        );
+    }
+}
+
+fn comparisons(latin_text: &str, tricks: bool, pretty_output: bool) {
+    let comparisons: Vec<WordComparisons> = latin_text
+        .split(" ")
+        .map(|word| find_comparisons(&sanitize_word(word), tricks))
+        .collect();
+
+    if pretty_output {
+        for word_comparisons in comparisons {
+            word_comparisons.display();
+        }
+    } else {
+        println!("{}", serde_json::to_string_pretty(&comparisons).unwrap());
+    }
+}
+
+fn verb_synopsis(value: &str, only_existing: bool, tricks: bool, pretty_output: bool) {
+    let values: Vec<&str> = value.split_whitespace().collect();
+    if values.len() != 3 {
+        println!("A synopsis needs a verb, a person and a number, ex: amo 3 singular");
+        std::process::exit(0);
+    }
+
+    let person = match values[1].chars().next().and_then(|c| c.to_digit(10)) {
+        Some(person) if (1..=3).contains(&person) => person as i8,
+        _ => {
+            println!("Person must be 1, 2 or 3, but {} was provided", values[1]);
+            std::process::exit(0);
+        }
+    };
+
+    let number = match values[2].to_lowercase().as_str() {
+        "s" | "sg" | "singular" => Number::Singular,
+        "p" | "pl" | "plural" => Number::Plural,
+        _ => {
+            println!(
+                "Number must be singular or plural, but {} was provided",
+                values[2]
+            );
+            std::process::exit(0);
+        }
+    };
+
+    let synopses = find_synopses(
+        &sanitize_word(values[0]),
+        person,
+        number,
+        only_existing,
+        tricks,
+    );
+
+    if pretty_output {
+        synopses.display();
+    } else {
+        println!("{}", serde_json::to_string_pretty(&synopses).unwrap());
+    }
+}
+
+fn pronoun_paradigms(words: Option<String>, pretty_output: bool) {
+    let paradigms: Vec<PronounParadigm> = match words {
+        Some(words) => words
+            .split(" ")
+            .flat_map(|word| find_pronoun_paradigms(&sanitize_word(word)))
+            .collect(),
+        None => all_pronoun_paradigms(),
+    };
+
+    if pretty_output {
+        if paradigms.is_empty() {
+            println!("No pronouns found");
+        }
+        for paradigm in paradigms {
+            paradigm.display();
+        }
+    } else {
+        println!("{}", serde_json::to_string_pretty(&paradigms).unwrap());
+    }
+}
+
+fn agreement(phrase: &str, gender: Option<String>, tricks: bool, pretty_output: bool) {
+    let words: Vec<String> = phrase.split_whitespace().map(sanitize_word).collect();
+    if words.len() < 2 {
+        println!("A phrase needs a noun and at least one adjective, ex: vir bonus");
+        std::process::exit(0);
+    }
+
+    let gender = gender.map(|gender| match parse_gender(&gender) {
+        Some(gender) => gender,
+        None => {
+            println!(
+                "Gender must be masculine, feminine or neuter, but {} was provided",
+                gender
+            );
+            std::process::exit(0);
+        }
+    });
+
+    let adjectives: Vec<&str> = words[1..].iter().map(|word| word.as_str()).collect();
+    let agreement_phrase = match agree(&words[0], &adjectives, gender, tricks) {
+        Ok(agreement_phrase) => agreement_phrase,
+        Err(error) => {
+            println!("{}", error);
+            std::process::exit(0);
+        }
+    };
+
+    if pretty_output {
+        agreement_phrase.display();
+    } else {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&agreement_phrase).unwrap()
+        );
+    }
+}
+
+fn sense_search(query: &str, filter: &WordFilter, max: usize, pretty_output: bool) {
+    let latin_dictionary = get_latin_dictionary();
+    let index = SenseIndex::new(&latin_dictionary);
+    let results = match index.search(query, filter, max) {
+        Ok(results) => results,
+        Err(error) => {
+            println!("{}", error);
+            std::process::exit(0);
+        }
+    };
+
+    if pretty_output {
+        if results.is_empty() {
+            println!("No words found for {}", query);
+        }
+        for result in results {
+            result.display();
+        }
+    } else {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+}
+
+fn word_family(latin_word: &str, tricks: bool, pretty_output: bool) {
+    let family = match find_family(&sanitize_word(latin_word), tricks) {
+        Ok(family) => family,
+        Err(error) => {
+            println!("{}", error);
+            std::process::exit(0);
+        }
+    };
+
+    if pretty_output {
+        family.display();
+    } else {
+        println!("{}", serde_json::to_string_pretty(&family).unwrap());
+    }
+}
+
+fn word_synonyms(latin_word: &str, tricks: bool, max: usize, pretty_output: bool) {
+    let groups = match find_synonyms(&sanitize_word(latin_word), tricks, max) {
+        Ok(groups) => groups,
+        Err(error) => {
+            println!("{}", error);
+            std::process::exit(0);
+        }
+    };
+
+    if pretty_output {
+        for group in groups {
+            group.display();
+        }
+    } else {
+        println!("{}", serde_json::to_string_pretty(&groups).unwrap());
+    }
+}
+
+fn show_entry(word_type: &WordType, id: i32) {
+    match find_by_id(word_type, id) {
+        Ok(record) => println!("{}", serde_json::to_string_pretty(&record).unwrap()),
+        Err(error) => println!("{}", error),
+    }
+}
+
+// the keys given with --sort-by, or the default key if only --sort is given
+fn sort_keys(command: &Command, default: SortKey) -> Vec<SortBy> {
+    match command.get_value_of("sort_by") {
+        ArgValue::Present(keys) => parse_sort_by(&keys).unwrap_or_else(|e| {
+            println!("{}", e);
+            std::process::exit(0);
+        }),
+        ArgValue::Missing(_) if command.has("sort") => vec![SortBy::new(default)],
+        ArgValue::Missing(_) => Vec::new(),
+    }
+}
+
+fn parse_pos_list(pos: ArgValue) -> Option<Vec<PartOfSpeech>> {
+    let pos_list = match pos {
+        ArgValue::Present(pos) => {
+            let pos_list: Vec<PartOfSpeech> = pos
+                .split(",")
+                .map(|pos| PartOfSpeech::dict_key_to_part_of_speech(pos.trim()))
+                .collect();
+            Some(pos_list)
+        }
+        ArgValue::Missing(_) => None,
+    };
+
+    if pos_list.is_some() && pos_list.as_ref().unwrap().contains(&PartOfSpeech::Unknown) {
+        println!("Invalid part of speech entered.");
+        println!("Please use the following: noun, verb, participle, adjective, preposition, pronoun, interjection, numeral, conjunction, adverb, number, supine, packon, tackon, prefix, suffix");
+        std::process::exit(0);
+    }
+
+    pos_list
+}
+
+fn parse_ages(ages: &str) -> Vec<Age> {
+    let ages: Vec<Age> = ages
+        .split(",")
+        .map(|age| Age::dict_key_to_age(age.trim()))
+        .collect();
+
+    if ages.contains(&Age::Unknown) {
+        println!("Invalid age entered.");
+        println!("Please use the following: archaic, early, classical, late, later, medieval, scholar, modern, all");
+        std::process::exit(0);
+    }
+
+    ages
+}
+
+fn parse_areas(areas: &str) -> Vec<Area> {
+    let areas: Vec<Area> = areas
+        .split(",")
+        .map(|area| Area::dict_key_to_area(area.trim()))
+        .collect();
+
+    if areas.contains(&Area::Unknown) {
+        println!("Invalid area entered.");
+        println!("Please use the following: agriculture, biological, art, religious, grammar, legal, poetic, scientific, technical, warfare, mythological, all");
+        std::process::exit(0);
+    }
+
+    areas
+}
+
+fn english_inflection(phrase: &str, pretty_output: bool) {
+    let latin_dictionary = get_latin_dictionary();
+    let english_dictionary = get_english_dictionary();
+    let inflections = inflect_english(&english_dictionary, &latin_dictionary, phrase);
+
+    if pretty_output {
+        if inflections.is_empty() {
+            println!("No latin forms found for {}", phrase);
+        }
+        for inflection in inflections {
+            inflection.display();
+        }
+    } else {
+        println!("{}", serde_json::to_string_pretty(&inflections).unwrap());
+    }
+}
+
 fn english_to_latin(
     english_text: &str,
     max: usize,
-    sort: bool,
+    sort_by: &[SortBy],
     pretty_output: bool,
     detailed_pretty_output: bool,
 ) {
     let english_words: Vec<&str> = english_text.split(" ").collect();
+    let sanitized_words: Vec<String> = english_words
+        .iter()
+        .map(|word| sanitize_word(word))
+        .collect();
     let mut translations: Vec<Translation> = Vec::new();
 
     let latin_dictionary = get_latin_dictionary();
     let english_dictionary = get_english_dictionary();
 
-    for word in english_words {
-        let definitions = translate_english_to_latin(
-            &english_dictionary,
-            &latin_dictionary,
-            &sanitize_word(word),
-            max,
-            sort,
+    let mut i = 0;
+    while i < english_words.len() {
+        // the longest phrase is tried before the single word, ex: take care, set out
+        let (length, definitions) =
+            match find_longest_phrase(&latin_dictionary, &sanitized_words[i..], max) {
+                Some((length, definitions)) => (length, definitions),
+                None => (
+                    1,
+                    translate_english_to_latin(
+                        &english_dictionary,
+                        &latin_dictionary,
+                        &sanitized_words[i],
+                        max,
+                        sort_by,
+                    ),
+                ),
+            };
+
+        let mut translation = Translation::new(
+            english_words[i..i + length].join(" "),
+            TranslationType::English(definitions),
         );
-        let mut translation =
-            Translation::new(word.to_string(), TranslationType::English(definitions));
-        translation.post_process(Language::English, sort);
+        translation.post_process(Language::English, sort_by);
         translations.push(translation);
+        i += length;
     }
 
     if pretty_output {
@@ -0,0 +1,324 @@
+use crate::dictionary_structures::dictionary_keys::{Declension, Gender, Number, PartOfSpeech};
+use crate::dictionary_structures::dictionary_values::LatinWordInfo;
+use crate::paradigms::comparison::positive_lemma;
+use crate::paradigms::pronouns::find_pronoun_paradigms;
+use crate::paradigms::{decline, word_gender, DeclensionTable, CASES, GENDERS, NUMBERS};
+use crate::translators::latin_to_english::trace::Trace;
+use crate::translators::latin_to_english::translate_latin_to_english;
+use crate::utils::data::get_latin_dictionary;
+use serde::Serialize;
+
+/**
+ * A noun declined together with the adjectives that agree with it, ex: vir bonus, viri boni
+ * Pronouns used as adjectives come before the noun, ex: haec res publica
+ */
+#[derive(Debug, Serialize)]
+pub struct AgreementPhrase {
+    pub phrase: String,
+    pub gender: String,
+    pub lemmas: Vec<String>,
+    pub table: DeclensionTable,
+}
+
+impl AgreementPhrase {
+    pub fn display(&self) {
+        println!("{} ({})", self.phrase, self.lemmas.join(", "));
+        self.table.display();
+        println!();
+    }
+}
+
+/**
+ * One word of the phrase with its full declension.
+ */
+struct Modifier {
+    lemma: String,
+    table: DeclensionTable,
+    is_pronoun: bool,
+}
+
+/**
+ * Declines a noun and the adjectives that go with it in every case and number.
+ * Each cell keeps the first form of every word, ex: servum bonum but not servom bonum
+ * The adjectives can be given in any form that agrees with the noun, ex: vir bonus, res publica
+ * A gender only has to be given for nouns that can be more than one, ex: civis
+ */
+pub fn agree(
+    noun: &str,
+    adjectives: &[&str],
+    gender: Option<Gender>,
+    tricks: bool,
+) -> Result<AgreementPhrase, String> {
+    let (noun_word, gender) = find_noun(noun, gender, tricks)?;
+    let noun_table = decline_noun(&noun_word, gender);
+    if noun_table.is_empty() {
+        return Err(format!("{} could not be declined", noun));
+    }
+
+    let modifiers = adjectives
+        .iter()
+        .map(|adjective| find_modifier(adjective, noun, gender, tricks))
+        .collect::<Result<Vec<Modifier>, String>>()?;
+
+    let noun_lemma = noun_lemma(&noun_word);
+
+    let (before, after): (Vec<&Modifier>, Vec<&Modifier>) =
+        modifiers.iter().partition(|modifier| modifier.is_pronoun);
+
+    let mut lemmas: Vec<String> = before
+        .iter()
+        .map(|modifier| modifier.lemma.clone())
+        .collect();
+    lemmas.push(noun_lemma);
+    lemmas.extend(after.iter().map(|modifier| modifier.lemma.clone()));
+
+    let mut table = DeclensionTable::new();
+    for number in NUMBERS {
+        for case in CASES {
+            // the first form of each word, ex: servum bonum but not servom bonum
+            let mut words: Vec<&[String]> = before
+                .iter()
+                .map(|modifier| modifier.table.get(case, number, gender))
+                .collect();
+            words.push(noun_table.get(case, number, gender));
+            words.extend(
+                after
+                    .iter()
+                    .map(|modifier| modifier.table.get(case, number, gender)),
+            );
+
+            // a case that one of the words does not have, ex: the locative of an adjective
+            let first_forms: Option<Vec<&str>> = words
+                .iter()
+                .map(|forms| forms.first().map(|form| form.as_str()))
+                .collect();
+            if let Some(first_forms) = first_forms {
+                table.add(case, number, gender, &first_forms.join(" "));
+            }
+        }
+    }
+
+    let phrase = table
+        .get(Declension::Nominative, Number::Singular, gender)
+        .first()
+        .cloned()
+        .unwrap_or_else(|| lemmas.join(" "));
+
+    Ok(AgreementPhrase {
+        phrase,
+        gender: gender.as_str().to_string(),
+        lemmas,
+        table,
+    })
+}
+
+/**
+ * Parses a gender given by the user, ex: f, fem, feminine
+ */
+pub fn parse_gender(gender: &str) -> Option<Gender> {
+    match gender.to_lowercase().as_str() {
+        "m" | "masc" | "masculine" => Some(Gender::Masculine),
+        "f" | "fem" | "feminine" => Some(Gender::Feminine),
+        "n" | "neut" | "neuter" => Some(Gender::Neuter),
+        _ => None,
+    }
+}
+
+fn find_noun(
+    latin_word: &str,
+    gender: Option<Gender>,
+    tricks: bool,
+) -> Result<(LatinWordInfo, Gender), String> {
+    let nouns: Vec<LatinWordInfo> =
+        translate_latin_to_english(latin_word, tricks, &mut Trace::new(false))
+            .into_iter()
+            .map(|definition| definition.word)
+            .filter(|word| word.pos == PartOfSpeech::Noun)
+            .collect();
+
+    if nouns.is_empty() {
+        return Err(format!("{} is not a noun", latin_word));
+    }
+
+    let mut candidates: Vec<(LatinWordInfo, Gender)> = Vec::new();
+    for noun in nouns {
+        for noun_gender in possible_genders(word_gender(&noun)) {
+            if gender.is_none_or(|gender| gender == noun_gender) {
+                candidates.push((noun.clone(), noun_gender));
+            }
+        }
+    }
+
+    // the nouns the word is the nominative of, ex: mare (the sea) but not mare (ablative of mas)
+    let nominatives: Vec<(LatinWordInfo, Gender)> = candidates
+        .iter()
+        .filter(|(noun, noun_gender)| {
+            decline_noun(noun, *noun_gender)
+                .get(Declension::Nominative, Number::Singular, *noun_gender)
+                .iter()
+                .any(|form| form == latin_word)
+        })
+        .cloned()
+        .collect();
+    if !nominatives.is_empty() {
+        candidates = nominatives;
+    }
+
+    // nouns of the same gender decline the same way, ex: the entries of a word with several meanings
+    let mut candidates: Vec<(LatinWordInfo, Gender)> =
+        candidates
+            .into_iter()
+            .fold(Vec::new(), |mut candidates, (noun, noun_gender)| {
+                if !candidates
+                    .iter()
+                    .any(|(_, existing)| *existing == noun_gender)
+                {
+                    candidates.push((noun, noun_gender));
+                }
+                candidates
+            });
+
+    match candidates.len() {
+        0 => Err(format!(
+            "{} is not {}",
+            latin_word,
+            gender.unwrap_or(Gender::Unknown).as_str()
+        )),
+        1 => Ok(candidates.remove(0)),
+        _ => Err(format!(
+            "The gender of {} is ambiguous, it can be {}. Use --gender to choose one",
+            latin_word,
+            candidates
+                .iter()
+                .map(|(_, gender)| gender.as_str())
+                .collect::<Vec<&str>>()
+                .join(" or ")
+        )),
+    }
+}
+
+fn noun_lemma(noun: &LatinWordInfo) -> String {
+    noun.principle_parts()
+        .ok()
+        .and_then(|parts| parts.first().cloned())
+        .unwrap_or_else(|| noun.orth.to_string())
+}
+
+/**
+ * Declines a noun with its dictionary form as the first nominative singular.
+ * Some declensions only have an older ending for the nominative, ex: servos for servus
+ */
+fn decline_noun(noun: &LatinWordInfo, gender: Gender) -> DeclensionTable {
+    let mut table = decline(noun, &[gender]);
+    let lemma = noun_lemma(noun);
+
+    if let Some(cell) = table.cells.iter_mut().find(|cell| {
+        cell.case == Declension::Nominative
+            && cell.number == Number::Singular
+            && cell.gender == gender
+    }) {
+        cell.forms.retain(|form| *form != lemma);
+        cell.forms.insert(0, lemma);
+    }
+
+    table
+}
+
+// finds the adjective or pronoun a word comes from, keeping only the ones with the word in the gender of the noun
+fn find_modifier(
+    latin_word: &str,
+    noun: &str,
+    gender: Gender,
+    tricks: bool,
+) -> Result<Modifier, String> {
+    let mut adjectives: Vec<LatinWordInfo> =
+        translate_latin_to_english(latin_word, tricks, &mut Trace::new(false))
+            .into_iter()
+            .map(|definition| definition.word)
+            .filter(|word| word.pos == PartOfSpeech::Adjective)
+            .collect();
+
+    // adjectives that share their spelling with a noun are analysed as the noun, ex: bonus
+    if adjectives.is_empty() {
+        adjectives = get_latin_dictionary()
+            .into_iter()
+            .filter(|word| {
+                word.pos == PartOfSpeech::Adjective
+                    && word.parts.iter().take(2).any(|stem| {
+                        stem != "zzz" && !stem.is_empty() && latin_word.starts_with(stem.as_str())
+                    })
+            })
+            .collect();
+    }
+
+    let mut candidates: Vec<Modifier> = adjectives
+        .iter()
+        .map(|adjective| Modifier {
+            lemma: positive_lemma(adjective).unwrap_or_else(|| adjective.orth.to_string()),
+            table: decline(adjective, &GENDERS[..3]),
+            is_pronoun: false,
+        })
+        .collect();
+    candidates.extend(
+        find_pronoun_paradigms(latin_word)
+            .into_iter()
+            // personal pronouns have no genders to agree in, ex: ego
+            .filter(|paradigm| !paradigm.table.genders().contains(&Gender::Common))
+            .map(|paradigm| Modifier {
+                lemma: paradigm
+                    .citation
+                    .split(", ")
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+                table: paradigm.table,
+                is_pronoun: true,
+            }),
+    );
+
+    let genders_of_word: Vec<Gender> = candidates
+        .iter()
+        .flat_map(|candidate| candidate.table.cells.iter())
+        .filter(|cell| cell.forms.iter().any(|form| form == latin_word))
+        .map(|cell| cell.gender)
+        .fold(Vec::new(), |mut genders, cell_gender| {
+            if !genders.contains(&cell_gender) {
+                genders.push(cell_gender);
+            }
+            genders
+        });
+
+    if genders_of_word.is_empty() {
+        return Err(format!("{} is not an adjective", latin_word));
+    }
+
+    candidates
+        .into_iter()
+        .find(|candidate| {
+            candidate.table.cells.iter().any(|cell| {
+                cell.gender == gender && cell.forms.iter().any(|form| form == latin_word)
+            })
+        })
+        .ok_or_else(|| {
+            format!(
+                "{} is {} and does not agree with {}, which is {}",
+                latin_word,
+                genders_of_word
+                    .iter()
+                    .map(|gender| gender.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" or "),
+                noun,
+                gender.as_str()
+            )
+        })
+}
+
+// a common noun can be masculine or feminine, ex: civis
+fn possible_genders(gender: Gender) -> Vec<Gender> {
+    match gender {
+        Gender::Masculine | Gender::Feminine | Gender::Neuter => vec![gender],
+        Gender::Common => vec![Gender::Masculine, Gender::Feminine],
+        Gender::Unknown => vec![Gender::Masculine, Gender::Feminine, Gender::Neuter],
+    }
+}
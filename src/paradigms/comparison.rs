@@ -0,0 +1,310 @@
+use crate::dictionary_structures::dictionary_keys::{Comparison, PartOfSpeech};
+use crate::dictionary_structures::dictionary_values::{Form, LatinWordInfo};
+use crate::translators::latin_to_english::trace::Trace;
+use crate::translators::latin_to_english::translate_latin_to_english;
+use crate::utils::data::get_latin_dictionary;
+use serde::{Deserialize, Serialize};
+
+// adverbs that are not built from the stems of their adjective: (adjective, positive, comparative, superlative)
+const IRREGULAR_ADVERBS: [(&str, &str, &str, &str); 6] = [
+    ("bonus", "bene", "melius", "optime"),
+    ("malus", "male", "peius", "pessime"),
+    ("magnus", "magnopere", "magis", "maxime"),
+    ("parvus", "paulum", "minus", "minime"),
+    ("multus", "multum", "plus", "plurimum"),
+    ("facilis", "facile", "facilius", "facillime"),
+];
+
+/**
+ * The forms of a word in each degree, a degree the word does not have is None.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Degrees {
+    pub positive: Option<String>,
+    pub comparative: Option<String>,
+    pub superlative: Option<String>,
+}
+
+impl Degrees {
+    pub fn get(&self, comparison: Comparison) -> Option<&String> {
+        match comparison {
+            Comparison::Positive => self.positive.as_ref(),
+            Comparison::Comparative => self.comparative.as_ref(),
+            Comparison::Superlative => self.superlative.as_ref(),
+            Comparison::Unknown => None,
+        }
+    }
+}
+
+/**
+ * The degrees of an adjective and of the adverb made from it.
+ * ex: bonus, melior, optimus and bene, melius, optime
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct AdjectiveComparison {
+    pub id: i32,
+    pub adjective: Degrees,
+    pub adverb: Degrees,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WordComparisons {
+    pub word: String,
+    pub entries: Vec<AdjectiveComparison>,
+}
+
+impl WordComparisons {
+    pub fn display(&self) {
+        println!("{}:", self.word);
+
+        if self.entries.is_empty() {
+            println!("No adjectives or adverbs found");
+        }
+
+        for entry in &self.entries {
+            let width = [&entry.adjective, &entry.adverb]
+                .iter()
+                .flat_map(|degrees| {
+                    [
+                        &degrees.positive,
+                        &degrees.comparative,
+                        &degrees.superlative,
+                    ]
+                })
+                .map(|form| form.as_ref().map_or(3, |form| form.len()))
+                .max()
+                .unwrap_or(0)
+                + 2;
+
+            println!(
+                "{}{:<width$}adverb",
+                " ".repeat(14),
+                "adjective",
+                width = width
+            );
+            for comparison in [
+                Comparison::Positive,
+                Comparison::Comparative,
+                Comparison::Superlative,
+            ] {
+                println!(
+                    "{:<14}{:<width$}{}",
+                    comparison.as_str(),
+                    entry.adjective.get(comparison).map_or("---", |form| form),
+                    entry.adverb.get(comparison).map_or("---", |form| form),
+                    width = width
+                );
+            }
+        }
+        println!();
+    }
+}
+
+/**
+ * Marks an analysis of a comparative or superlative form with the positive it comes from.
+ * ex: melior -> comparative of bonus
+ */
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ComparedForm {
+    pub comparison: String,
+    pub positive: String,
+}
+
+/**
+ * Builds the degrees of an adjective from its dictionary stems, ex: bon, bon, meli, opti
+ * Adjectives with a single degree only have that one, ex: ulterior (comparative)
+ */
+pub fn compare_adjective(word: &LatinWordInfo) -> Option<AdjectiveComparison> {
+    if word.pos != PartOfSpeech::Adjective || word.parts.is_empty() {
+        return None;
+    }
+
+    let stem = |index: usize| {
+        word.parts
+            .get(index)
+            .filter(|part| part.as_str() != "zzz" && !part.is_empty())
+    };
+
+    let (positive_stem, comparative_stem, superlative_stem) = match word_comparison(word) {
+        Comparison::Positive => (stem(1), None, None),
+        Comparison::Comparative => (None, stem(0), None),
+        Comparison::Superlative => (None, None, stem(0)),
+        Comparison::Unknown => (stem(1), stem(2), stem(3)),
+    };
+
+    let positive = positive_stem.and_then(|_| positive_parts(word));
+    let adjective = Degrees {
+        positive: positive.as_ref().map(|parts| parts.join(", ")),
+        comparative: comparative_stem.map(|stem| format!("{}or, {}us", stem, stem)),
+        superlative: superlative_stem.map(|stem| format!("{}mus, {}ma, {}mum", stem, stem, stem)),
+    };
+
+    let irregular_adverb = positive.as_ref().and_then(|parts| {
+        IRREGULAR_ADVERBS
+            .iter()
+            .find(|(adjective, _, _, _)| *adjective == parts[0])
+    });
+
+    let adverb = match irregular_adverb {
+        Some((_, positive, comparative, superlative)) => Degrees {
+            positive: Some(positive.to_string()),
+            comparative: Some(comparative.to_string()),
+            superlative: Some(superlative.to_string()),
+        },
+        None => Degrees {
+            positive: positive_stem.map(|stem| positive_adverb(word, stem)),
+            // the comparative adverb is the neuter of the comparative, ex: fortius
+            comparative: comparative_stem.map(|stem| format!("{}us", stem)),
+            superlative: superlative_stem.map(|stem| format!("{}me", stem)),
+        },
+    };
+
+    Some(AdjectiveComparison {
+        id: word.id,
+        adjective,
+        adverb,
+    })
+}
+
+/**
+ * The citation form of the positive of an adjective, ex: bonus
+ */
+pub fn positive_lemma(word: &LatinWordInfo) -> Option<String> {
+    positive_parts(word).map(|parts| parts[0].to_string())
+}
+
+/**
+ * Notes the degree of an analysed adjective form that is built on the comparative or superlative stem.
+ */
+pub fn compared_form(word: &LatinWordInfo, stem_keys: &[i8]) -> Option<ComparedForm> {
+    if word.pos != PartOfSpeech::Adjective {
+        return None;
+    }
+
+    let comparison = if stem_keys.contains(&3) {
+        Comparison::Comparative
+    } else if stem_keys.contains(&4) {
+        Comparison::Superlative
+    } else {
+        return None;
+    };
+
+    Some(ComparedForm {
+        comparison: comparison.as_str().to_string(),
+        positive: positive_lemma(word)?,
+    })
+}
+
+/**
+ * Finds the adjectives a latin word comes from, or that an adverb is made from, and compares them.
+ * ex: melius -> bonus, melior, optimus; bene, melius, optime
+ */
+pub fn find_comparisons(latin_word: &str, tricks: bool) -> WordComparisons {
+    let definitions = translate_latin_to_english(latin_word, tricks, &mut Trace::new(false));
+    let mut entries: Vec<AdjectiveComparison> = Vec::new();
+
+    for definition in &definitions {
+        if let Some(comparison) = compare_adjective(&definition.word) {
+            push_comparison(&mut entries, comparison);
+        }
+    }
+
+    let is_adverb = definitions
+        .iter()
+        .any(|definition| definition.word.pos == PartOfSpeech::Adverb);
+    if is_adverb || entries.is_empty() {
+        for word in get_latin_dictionary() {
+            if word.pos != PartOfSpeech::Adjective
+                || entries.iter().any(|entry| entry.id == word.id)
+            {
+                continue;
+            }
+
+            if let Some(comparison) = compare_adjective(&word) {
+                let adverb = &comparison.adverb;
+                let is_adverb_form = [&adverb.positive, &adverb.comparative, &adverb.superlative]
+                    .iter()
+                    .any(|form| form.as_deref() == Some(latin_word));
+                // adjectives that share their spelling with a noun are analysed as the noun, ex: miser
+                let is_positive = comparison
+                    .adjective
+                    .positive
+                    .as_deref()
+                    .and_then(|positive| positive.split(", ").next())
+                    == Some(latin_word);
+
+                if is_adverb_form || (is_positive && entries.is_empty()) {
+                    push_comparison(&mut entries, comparison);
+                }
+            }
+        }
+    }
+
+    WordComparisons {
+        word: latin_word.to_string(),
+        entries,
+    }
+}
+
+// entries of the same adjective give the same forms, ex: the three entries of magnus
+fn push_comparison(entries: &mut Vec<AdjectiveComparison>, comparison: AdjectiveComparison) {
+    let is_duplicate = entries.iter().any(|entry| {
+        entry.id == comparison.id
+            || (entry.adjective == comparison.adjective && entry.adverb == comparison.adverb)
+    });
+
+    if !is_duplicate {
+        entries.push(comparison);
+    }
+}
+
+fn word_comparison(word: &LatinWordInfo) -> Comparison {
+    match &word.form {
+        Form::LongForm(form) => form.comparison.unwrap_or(Comparison::Unknown),
+        Form::StrForm(form) => match form.split_whitespace().nth(2) {
+            Some(comparison) => Comparison::dict_key_to_comparison(comparison),
+            None => Comparison::Unknown,
+        },
+    }
+}
+
+// the principle parts of the positive alone, ex: bonus, bona, bonum
+fn positive_parts(word: &LatinWordInfo) -> Option<Vec<String>> {
+    let n = word.n.as_ref().filter(|n| n.len() >= 2)?;
+    let comparison = word_comparison(word);
+    if comparison != Comparison::Positive && comparison != Comparison::Unknown {
+        return None;
+    }
+
+    let mut positive = word.clone();
+    positive.parts.truncate(2);
+    positive.form = Form::StrForm(format!(
+        "{} {} POS",
+        n[0].get_n_value_1(),
+        n[1].get_n_value_2()
+    ));
+
+    positive
+        .principle_parts()
+        .ok()
+        .filter(|parts| !parts.is_empty() && parts[0] != "---")
+}
+
+// longus -> longe; fortis -> fortiter; prudens -> prudenter
+fn positive_adverb(word: &LatinWordInfo, stem: &str) -> String {
+    let declension = word
+        .n
+        .as_ref()
+        .and_then(|n| n.first())
+        .map_or(0, |n| n.get_n_value_1());
+
+    if declension == 3 {
+        if stem.ends_with("nt") {
+            format!("{}er", stem)
+        } else {
+            format!("{}iter", stem)
+        }
+    } else {
+        format!("{}e", stem)
+    }
+}
@@ -0,0 +1,384 @@
+// builds full tables of forms from the stems of a word and the inflection data
+pub mod agreement;
+pub mod comparison;
+pub mod pronouns;
+pub mod synopsis;
+
+use crate::dictionary_structures::dictionary_keys::{Declension, Gender, Number, PartOfSpeech};
+use crate::dictionary_structures::dictionary_values::{Form, Inflection, LatinWordInfo};
+use crate::paradigms::synopsis::conjugate;
+use crate::utils::data::get_latin_inflections;
+use serde::Serialize;
+use std::sync::OnceLock;
+
+// parsed once, a list of words looks the inflections up for every word
+static LATIN_INFLECTIONS: OnceLock<Vec<Inflection>> = OnceLock::new();
+
+pub const CASES: [Declension; 7] = [
+    Declension::Nominative,
+    Declension::Genitive,
+    Declension::Dative,
+    Declension::Accusative,
+    Declension::Ablative,
+    Declension::Vocative,
+    Declension::Locative,
+];
+
+pub const NUMBERS: [Number; 2] = [Number::Singular, Number::Plural];
+
+pub const GENDERS: [Gender; 4] = [
+    Gender::Masculine,
+    Gender::Feminine,
+    Gender::Neuter,
+    Gender::Common,
+];
+
+/**
+ * One cell of a declension table, a cell can have more than one form, ex: nostrum/nostri
+ */
+#[derive(Debug, Clone)]
+pub struct DeclensionCell {
+    pub case: Declension,
+    pub number: Number,
+    pub gender: Gender,
+    pub forms: Vec<String>,
+}
+
+/**
+ * The forms of a declined word by case, number and gender.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct DeclensionTable {
+    pub cells: Vec<DeclensionCell>,
+}
+
+impl DeclensionTable {
+    pub fn new() -> DeclensionTable {
+        DeclensionTable::default()
+    }
+
+    pub fn add(&mut self, case: Declension, number: Number, gender: Gender, form: &str) {
+        let cell = self
+            .cells
+            .iter_mut()
+            .find(|cell| cell.case == case && cell.number == number && cell.gender == gender);
+
+        match cell {
+            Some(cell) => {
+                if !cell.forms.iter().any(|existing| existing == form) {
+                    cell.forms.push(form.to_string());
+                }
+            }
+            None => self.cells.push(DeclensionCell {
+                case,
+                number,
+                gender,
+                forms: vec![form.to_string()],
+            }),
+        }
+    }
+
+    pub fn get(&self, case: Declension, number: Number, gender: Gender) -> &[String] {
+        self.cells
+            .iter()
+            .find(|cell| cell.case == case && cell.number == number && cell.gender == gender)
+            .map(|cell| cell.forms.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn genders(&self) -> Vec<Gender> {
+        GENDERS
+            .into_iter()
+            .filter(|gender| self.cells.iter().any(|cell| cell.gender == *gender))
+            .collect()
+    }
+
+    /**
+     * Prints the table with a row for each case and a column for each gender.
+     */
+    pub fn display(&self) {
+        let genders = self.genders();
+        let width = self
+            .cells
+            .iter()
+            .map(|cell| cell.forms.join("/").len())
+            .chain(genders.iter().map(|gender| gender.as_str().len()))
+            .max()
+            .unwrap_or(0)
+            + 2;
+
+        print!("{:<12}", "");
+        for gender in &genders {
+            print!("{:<width$}", gender.as_str(), width = width);
+        }
+        println!();
+
+        for number in NUMBERS {
+            if !self.cells.iter().any(|cell| cell.number == number) {
+                continue;
+            }
+
+            println!("{}", number.as_str());
+            for case in CASES {
+                if !self
+                    .cells
+                    .iter()
+                    .any(|cell| cell.number == number && cell.case == case)
+                {
+                    continue;
+                }
+
+                print!("{:<12}", case.as_str());
+                for gender in &genders {
+                    let forms = self.get(case, number, *gender);
+                    let forms = if forms.is_empty() {
+                        "-".to_string()
+                    } else {
+                        forms.join("/")
+                    };
+                    print!("{:<width$}", forms, width = width);
+                }
+                println!();
+            }
+        }
+    }
+}
+
+// {"singular": {"nominative": {"masculine": ["is"], ...}, ...}, ...}
+impl Serialize for DeclensionTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut map = serde_json::Map::new();
+        for number in NUMBERS {
+            let mut cases = serde_json::Map::new();
+            for case in CASES {
+                let mut genders = serde_json::Map::new();
+                for cell in &self.cells {
+                    if cell.number == number && cell.case == case {
+                        genders.insert(
+                            cell.gender.as_str().to_string(),
+                            serde_json::Value::Array(
+                                cell.forms
+                                    .iter()
+                                    .map(|form| serde_json::Value::String(form.to_string()))
+                                    .collect(),
+                            ),
+                        );
+                    }
+                }
+
+                if !genders.is_empty() {
+                    cases.insert(
+                        case.as_str().to_string(),
+                        serde_json::Value::Object(genders),
+                    );
+                }
+            }
+
+            if !cases.is_empty() {
+                map.insert(
+                    number.as_str().to_string(),
+                    serde_json::Value::Object(cases),
+                );
+            }
+        }
+
+        serde_json::Value::Object(map).serialize(serializer)
+    }
+}
+
+/**
+ * The inflections of a part of speech that apply to a declension or conjugation.
+ * Inflections with a second number of 0 apply to every variant, ex: (3, 0) for all 3rd declension nouns
+ * and ones with a first number of 0 to every declension, ex: (0, 0) for the perfect of every verb
+ */
+pub fn inflections_for(pos: PartOfSpeech, num_type_1: i8, num_types_2: &[i8]) -> Vec<Inflection> {
+    LATIN_INFLECTIONS
+        .get_or_init(get_latin_inflections)
+        .iter()
+        .filter(|inflection| {
+            let n = match &inflection.n {
+                Some(n) if n.len() >= 2 => n,
+                _ => return false,
+            };
+
+            inflection.pos == pos
+                && (n[0].get_n_value_1() == 0 || n[0].get_n_value_1() == num_type_1)
+                && (n[1].get_n_value_2() == 0 || num_types_2.contains(&n[1].get_n_value_2()))
+        })
+        .cloned()
+        .collect()
+}
+
+/**
+ * Splits the case, number and gender keys of an inflection form, ex: "GEN S X"
+ * X stands for every gender or both numbers, and C for masculine and feminine.
+ * Words that are never split by gender keep C and X as common, ex: ego
+ */
+pub fn case_number_gender(
+    form: &str,
+    split_genders: bool,
+) -> Option<(Declension, Vec<Number>, Vec<Gender>)> {
+    let form_array: Vec<&str> = form.split_whitespace().collect();
+    if form_array.len() < 3 {
+        return None;
+    }
+
+    let case = Declension::dict_key_to_declension(form_array[0]);
+    if case == Declension::Unknown {
+        return None;
+    }
+
+    let numbers = match form_array[1] {
+        "X" => NUMBERS.to_vec(),
+        number => vec![Number::dict_key_to_number(number)],
+    };
+
+    let genders = match form_array[2] {
+        "X" | "C" if !split_genders => vec![Gender::Common],
+        "X" => vec![Gender::Masculine, Gender::Feminine, Gender::Neuter],
+        "C" => vec![Gender::Masculine, Gender::Feminine],
+        gender => vec![Gender::dict_key_to_gender(gender)],
+    };
+
+    Some((case, numbers, genders))
+}
+
+/**
+ * Declines a noun, or the positive of an adjective, in the given genders.
+ */
+pub fn decline(word: &LatinWordInfo, genders: &[Gender]) -> DeclensionTable {
+    let mut table = DeclensionTable::new();
+    let (num_type_1, num_type_2) = match &word.n {
+        Some(n) if n.len() >= 2 => (n[0].get_n_value_1(), n[1].get_n_value_2()),
+        _ => return table,
+    };
+
+    for inflection in inflections_for(word.pos, num_type_1, &[num_type_2]) {
+        // the comparative and superlative are on their own stems
+        if inflection.stem_key.is_some_and(|stem_key| stem_key > 2) {
+            continue;
+        }
+
+        let (case, numbers, inflection_genders) =
+            match case_number_gender(&inflection.form.as_str(), true) {
+                Some(keys) => keys,
+                None => continue,
+            };
+
+        let ending = inflection
+            .ending
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+        for number in numbers {
+            for gender in genders {
+                if !inflection_genders.contains(gender) {
+                    continue;
+                }
+
+                let stem_key = stem_key(word.pos, &inflection, case, number, *gender, ending);
+                let stem = match word.parts.get(stem_key - 1) {
+                    Some(stem) if stem != "zzz" && !stem.is_empty() => stem,
+                    _ => continue,
+                };
+
+                table.add(
+                    case,
+                    number,
+                    *gender,
+                    &format!("{}{}", stem, ending).to_lowercase(),
+                );
+            }
+        }
+    }
+
+    table
+}
+
+/**
+ * Which stem an ending goes on when the inflection does not say.
+ * The nominative and vocative singular of nouns are on the first stem, ex: rex, regis
+ * and so are the endingless forms of 3rd declension adjectives and neuters, ex: ingens, nomen
+ */
+fn stem_key(
+    pos: PartOfSpeech,
+    inflection: &Inflection,
+    case: Declension,
+    number: Number,
+    gender: Gender,
+    ending: &str,
+) -> usize {
+    if let Some(stem_key) = inflection.stem_key {
+        return stem_key as usize;
+    }
+
+    let is_nominative = case == Declension::Nominative
+        || case == Declension::Vocative
+        || (case == Declension::Accusative && gender == Gender::Neuter);
+
+    if number == Number::Singular
+        && is_nominative
+        && (ending.is_empty() || pos == PartOfSpeech::Noun)
+    {
+        1
+    } else {
+        2
+    }
+}
+
+/**
+ * Every form that can be built from the stems of a word, ex: amo, amas, amat, amare, amatus
+ * Compound forms are left out, ex: amatus est
+ */
+pub fn all_forms(word: &LatinWordInfo) -> Vec<String> {
+    let mut forms: Vec<String> = Vec::new();
+    match word.pos {
+        PartOfSpeech::Noun => {
+            let genders = match word_gender(word) {
+                Gender::Common => vec![Gender::Masculine, Gender::Feminine],
+                Gender::Unknown => GENDERS[..3].to_vec(),
+                gender => vec![gender],
+            };
+            forms.extend(
+                decline(word, &genders)
+                    .cells
+                    .into_iter()
+                    .flat_map(|cell| cell.forms),
+            );
+        }
+        PartOfSpeech::Adjective | PartOfSpeech::Pronoun | PartOfSpeech::Numeral => {
+            forms.extend(
+                decline(word, &GENDERS)
+                    .cells
+                    .into_iter()
+                    .flat_map(|cell| cell.forms),
+            );
+        }
+        PartOfSpeech::Verb => forms.extend(conjugate(word)),
+        _ => {}
+    }
+
+    forms.into_iter().fold(Vec::new(), |mut forms, form| {
+        if !forms.contains(&form) {
+            forms.push(form);
+        }
+        forms
+    })
+}
+
+pub fn word_gender(word: &LatinWordInfo) -> Gender {
+    match &word.form {
+        Form::LongForm(form) => form.gender.unwrap_or(Gender::Unknown),
+        Form::StrForm(form) => match form.split_whitespace().nth(2) {
+            Some(gender) => Gender::dict_key_to_gender(gender),
+            None => Gender::Unknown,
+        },
+    }
+}
@@ -0,0 +1,521 @@
+use crate::dictionary_structures::dictionary_keys::{Declension, Number, PartOfSpeech, Pronoun};
+use crate::dictionary_structures::dictionary_values::{Attachment, Form, Inflection};
+use crate::paradigms::{case_number_gender, inflections_for, DeclensionTable, GENDERS, NUMBERS};
+use crate::utils::data::{
+    get_latin_dictionary, get_latin_not_packons, get_latin_packons, get_unique_latin_words,
+};
+use serde::Serialize;
+
+/**
+ * The full declension of a pronoun, ex: is, ea, id
+ * Compounds keep the part that is added to every form, ex: -dam for quidam
+ */
+#[derive(Debug, Clone)]
+pub struct PronounParadigm {
+    pub citation: String,
+    pub kind: Pronoun,
+    pub compound: Option<String>,
+    pub senses: Vec<String>,
+    pub ids: Vec<i32>,
+    pub table: DeclensionTable,
+}
+
+impl PronounParadigm {
+    pub fn display(&self) {
+        println!("{}", self.citation);
+        println!("{} pronoun", self.kind.as_str());
+        for sense in &self.senses {
+            print!("{} ", sense);
+        }
+        println!("\n");
+        self.table.display();
+        println!();
+    }
+}
+
+impl Serialize for PronounParadigm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut map = serde_json::Map::new();
+        map.insert(
+            "citation".to_string(),
+            serde_json::Value::String(self.citation.to_string()),
+        );
+        map.insert(
+            "kind".to_string(),
+            serde_json::Value::String(self.kind.as_str().to_string()),
+        );
+        map.insert(
+            "compound".to_string(),
+            match &self.compound {
+                Some(compound) => serde_json::Value::String(compound.to_string()),
+                None => serde_json::Value::Null,
+            },
+        );
+        map.insert(
+            "senses".to_string(),
+            serde_json::Value::Array(
+                self.senses
+                    .iter()
+                    .map(|sense| serde_json::Value::String(sense.to_string()))
+                    .collect(),
+            ),
+        );
+        map.insert(
+            "ids".to_string(),
+            serde_json::Value::Array(
+                self.ids
+                    .iter()
+                    .map(|id| serde_json::Value::Number((*id).into()))
+                    .collect(),
+            ),
+        );
+        map.insert(
+            "table".to_string(),
+            serde_json::to_value(&self.table).unwrap(),
+        );
+        serde_json::Value::Object(map).serialize(serializer)
+    }
+}
+
+/**
+ * The dictionary entries of one pronoun, the second number of each entry adds its own endings.
+ * ex: qu, cu 1 0 REL and qu 1 1 REL (qui), 1 4 REL (quae), 1 7 REL (quod) are all qui, quae, quod
+ */
+struct PronounEntries {
+    stems: [String; 2],
+    kind: Pronoun,
+    num_type_1: i8,
+    num_types_2: Vec<i8>,
+    ids: Vec<i32>,
+    senses: Vec<String>,
+}
+
+/**
+ * An attachment that is added to every form of a pronoun, ex: -dam (quidam), -dem (idem)
+ */
+struct Compound {
+    suffix: String,
+    num_type_1: i8,
+    num_type_2: i8,
+    kind: Pronoun,
+    senses: Vec<String>,
+}
+
+/**
+ * Finds the pronouns a word is the nominative of, or any form of when it is not a nominative,
+ * and builds their full tables, ex: is, hic, qui, quidam, aliquis, ego, nos, sui
+ */
+pub fn find_pronoun_paradigms(word: &str) -> Vec<PronounParadigm> {
+    let word = word.to_lowercase();
+    let entries = pronoun_entries();
+
+    let mut candidates: Vec<Option<Compound>> = vec![None];
+    for compound in compounds() {
+        if word.len() > compound.suffix.len() && word.ends_with(&compound.suffix) {
+            candidates.push(Some(compound));
+        }
+    }
+
+    let mut nominative_matches: Vec<PronounParadigm> = Vec::new();
+    let mut form_matches: Vec<PronounParadigm> = Vec::new();
+    for compound in &candidates {
+        for pronoun in &entries {
+            if let Some(compound) = compound {
+                let kind_matches =
+                    compound.kind == Pronoun::Unknown || compound.kind == pronoun.kind;
+                let n_matches = compound.num_type_1 == pronoun.num_type_1
+                    && (compound.num_type_2 == 0
+                        || pronoun.num_types_2.contains(&compound.num_type_2));
+                if !kind_matches || !n_matches {
+                    continue;
+                }
+            }
+
+            let paradigm = build_paradigm(pronoun, compound.as_ref());
+            let contains = |case: Option<Declension>| {
+                paradigm.table.cells.iter().any(|cell| {
+                    case.is_none_or(|case| cell.case == case) && cell.forms.contains(&word)
+                })
+            };
+
+            if contains(Some(Declension::Nominative)) {
+                nominative_matches.push(paradigm);
+            } else if contains(None) {
+                form_matches.push(paradigm);
+            }
+        }
+    }
+
+    if nominative_matches.is_empty() {
+        form_matches
+    } else {
+        nominative_matches
+    }
+}
+
+/**
+ * Builds the table of every pronoun in the dictionary, without compounds.
+ */
+pub fn all_pronoun_paradigms() -> Vec<PronounParadigm> {
+    pronoun_entries()
+        .iter()
+        .map(|pronoun| build_paradigm(pronoun, None))
+        .filter(|paradigm| !paradigm.table.is_empty())
+        .collect()
+}
+
+fn build_paradigm(pronoun: &PronounEntries, compound: Option<&Compound>) -> PronounParadigm {
+    let suffix = compound.map_or("", |compound| compound.suffix.as_str());
+    // qua (3 and 9) is only used after si, ne, num and ali-, ex: quadam is only the ablative of quidam
+    let num_types_2: Vec<i8> = pronoun
+        .num_types_2
+        .iter()
+        .copied()
+        .filter(|num_type_2| {
+            compound.is_none() || pronoun.num_type_1 != 1 || ![3, 9].contains(num_type_2)
+        })
+        .collect();
+    let inflections = inflections_for(PartOfSpeech::Pronoun, pronoun.num_type_1, &num_types_2);
+    let numbers = pronoun_numbers(pronoun);
+
+    // personal pronouns have the same forms for every gender
+    let split_genders = inflections.iter().any(|inflection| {
+        let form = inflection.form.as_str();
+        let gender = form.split_whitespace().nth(2).unwrap_or_default();
+        ["M", "F", "N"].contains(&gender)
+    });
+
+    let mut table = DeclensionTable::new();
+    for inflection in &inflections {
+        add_inflection(
+            &mut table,
+            pronoun,
+            inflection,
+            suffix,
+            split_genders,
+            &numbers,
+        );
+    }
+
+    // irregular forms that the inflections do not build, ex: eadem
+    for unique in get_unique_latin_words() {
+        if unique.pos != PartOfSpeech::Pronoun {
+            continue;
+        }
+
+        let form = unique.form.as_str();
+        let form_array: Vec<&str> = form.split_whitespace().collect();
+        if form_array.len() < 6 {
+            continue;
+        }
+
+        let num_type_1 = form_array[0].parse::<i8>().unwrap_or(0);
+        let num_type_2 = form_array[1].parse::<i8>().unwrap_or(0);
+        if num_type_1 != pronoun.num_type_1
+            || !num_types_2.contains(&num_type_2)
+            || Pronoun::dict_key_to_pronoun(form_array[5]) != pronoun.kind
+        {
+            continue;
+        }
+
+        let orth = unique.orth.to_lowercase();
+        let is_built_like_table = orth.strip_suffix(suffix).is_some_and(|without_suffix| {
+            is_stem_and_ending(without_suffix, pronoun, &inflections)
+        });
+        if !is_built_like_table {
+            continue;
+        }
+
+        let keys = form_array[2..5].join(" ");
+        // a form given for every gender only fills the cells the inflections left empty, ex: eodem is not feminine
+        let every_gender = ["X", "C"].contains(&form_array[4]) && split_genders;
+        if let Some((case, unique_numbers, genders)) = case_number_gender(&keys, split_genders) {
+            for number in unique_numbers {
+                if !numbers.contains(&number) {
+                    continue;
+                }
+
+                for gender in &genders {
+                    if every_gender && !table.get(case, number, *gender).is_empty() {
+                        continue;
+                    }
+                    table.add(case, number, *gender, &orth);
+                }
+            }
+        }
+    }
+
+    let senses = match compound {
+        Some(compound) => compound.senses.to_vec(),
+        None => pronoun.senses.to_vec(),
+    };
+
+    PronounParadigm {
+        citation: citation(&table),
+        kind: pronoun.kind,
+        compound: compound.map(|compound| compound.suffix.to_string()),
+        senses,
+        ids: pronoun.ids.to_vec(),
+        table,
+    }
+}
+
+fn add_inflection(
+    table: &mut DeclensionTable,
+    pronoun: &PronounEntries,
+    inflection: &Inflection,
+    suffix: &str,
+    split_genders: bool,
+    pronoun_numbers: &[Number],
+) {
+    let form = inflection.form.as_str();
+    let (case, numbers, genders) = match case_number_gender(&form, split_genders) {
+        Some(keys) => keys,
+        None => return,
+    };
+    let gender_key = form.split_whitespace().nth(2).unwrap_or_default();
+
+    for number in numbers {
+        if !pronoun_numbers.contains(&number) {
+            continue;
+        }
+
+        for stem_key in stem_keys(pronoun, case, number, gender_key, &inflection.ending) {
+            let stem = &pronoun.stems[stem_key - 1];
+            if stem == "zzz" {
+                continue;
+            }
+
+            let word = format!("{}{}{}", stem, inflection.ending, suffix);
+            for gender in &genders {
+                table.add(case, number, *gender, &word);
+            }
+        }
+    }
+}
+
+/**
+ * Which of the two stems an ending is added to.
+ * The inflection data does not keep the stem key, so it follows the pattern of each declension.
+ */
+fn stem_keys(
+    pronoun: &PronounEntries,
+    case: Declension,
+    number: Number,
+    gender_key: &str,
+    ending: &str,
+) -> Vec<usize> {
+    let is_singular = number == Number::Singular;
+
+    match pronoun.num_type_1 {
+        // qui, cuius, cui; hic, huius, huic
+        1 | 3 => {
+            if is_singular && (case == Declension::Genitive || case == Declension::Dative) {
+                vec![2]
+            } else {
+                vec![1]
+            }
+        }
+        // is, ea, id, eius, eum; ii/ei, iis/eis
+        4 => {
+            let is_first_stem = (is_singular
+                && case == Declension::Nominative
+                && (gender_key == "M" || gender_key == "N"))
+                || (is_singular && case == Declension::Accusative && gender_key == "N")
+                || (!is_singular && ending == "s");
+            let is_either_stem = !is_singular
+                && ((case == Declension::Nominative && ending == "i")
+                    || ((case == Declension::Dative || case == Declension::Ablative)
+                        && ending == "is"));
+
+            if is_either_stem {
+                vec![1, 2]
+            } else if is_first_stem {
+                vec![1]
+            } else {
+                vec![2]
+            }
+        }
+        // ego, mei; tu, tui; nos, nostrum; sui
+        5 => {
+            if pronoun.num_types_2.contains(&3) {
+                if case == Declension::Genitive {
+                    vec![2]
+                } else {
+                    vec![1]
+                }
+            } else if pronoun.num_types_2.contains(&4) {
+                vec![2]
+            } else if case == Declension::Nominative
+                || (case == Declension::Vocative && ending.is_empty())
+            {
+                // the vocative of ego is mi, but the vocative of tu is tu
+                vec![1]
+            } else {
+                vec![2]
+            }
+        }
+        _ => {
+            if is_singular && case == Declension::Nominative {
+                vec![1]
+            } else {
+                vec![2]
+            }
+        }
+    }
+}
+
+// the plurals of ego and tu are their own entries, ex: nos, vos
+fn pronoun_numbers(pronoun: &PronounEntries) -> Vec<Number> {
+    if pronoun.num_type_1 != 5 {
+        return NUMBERS.to_vec();
+    }
+
+    if pronoun
+        .num_types_2
+        .iter()
+        .all(|num_type_2| [1, 2].contains(num_type_2))
+    {
+        vec![Number::Singular]
+    } else if pronoun
+        .num_types_2
+        .iter()
+        .all(|num_type_2| *num_type_2 == 3)
+    {
+        vec![Number::Plural]
+    } else {
+        NUMBERS.to_vec()
+    }
+}
+
+fn is_stem_and_ending(word: &str, pronoun: &PronounEntries, inflections: &[Inflection]) -> bool {
+    pronoun.stems.iter().any(|stem| {
+        stem != "zzz"
+            && word.strip_prefix(stem.as_str()).is_some_and(|ending| {
+                inflections
+                    .iter()
+                    .any(|inflection| inflection.ending == ending)
+            })
+    })
+}
+
+// the nominative singular of each gender, ex: hic, haec, hoc
+fn citation(table: &DeclensionTable) -> String {
+    for number in NUMBERS {
+        let mut forms: Vec<String> = Vec::new();
+        for gender in GENDERS {
+            if let Some(form) = table.get(Declension::Nominative, number, gender).first() {
+                if !forms.contains(form) {
+                    forms.push(form.to_string());
+                }
+            }
+        }
+
+        if !forms.is_empty() {
+            return forms.join(", ");
+        }
+    }
+
+    // sui has no nominative
+    table
+        .cells
+        .first()
+        .and_then(|cell| cell.forms.first())
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn pronoun_entries() -> Vec<PronounEntries> {
+    let mut entries: Vec<PronounEntries> = Vec::new();
+
+    for word in get_latin_dictionary() {
+        if word.pos != PartOfSpeech::Pronoun || word.parts.len() < 2 {
+            continue;
+        }
+
+        let form = match &word.form {
+            Form::StrForm(form) => form.to_string(),
+            Form::LongForm(_) => continue,
+        };
+        let form_array: Vec<&str> = form.split_whitespace().collect();
+        if form_array.len() < 3 {
+            continue;
+        }
+
+        let num_type_1 = form_array[0].parse::<i8>().unwrap_or(0);
+        let num_type_2 = form_array[1].parse::<i8>().unwrap_or(0);
+        let kind = Pronoun::dict_key_to_pronoun(form_array[2]);
+        // undeclined and abbreviated pronouns have no table
+        if num_type_1 == 9 {
+            continue;
+        }
+
+        let stem_1 = word.parts[0].trim().to_string();
+        let stem_2 = word.parts[1].trim().to_string();
+
+        let existing = entries.iter_mut().find(|entry| {
+            entry.stems[0] == stem_1 && entry.kind == kind && entry.num_type_1 == num_type_1
+        });
+
+        match existing {
+            Some(entry) => {
+                if entry.stems[1] == "zzz" {
+                    entry.stems[1] = stem_2;
+                }
+                if !entry.num_types_2.contains(&num_type_2) {
+                    entry.num_types_2.push(num_type_2);
+                }
+                if !entry.ids.contains(&word.id) {
+                    entry.ids.push(word.id);
+                }
+            }
+            None => entries.push(PronounEntries {
+                stems: [stem_1, stem_2],
+                kind,
+                num_type_1,
+                num_types_2: vec![num_type_2],
+                ids: vec![word.id],
+                senses: word.senses.to_vec(),
+            }),
+        }
+    }
+
+    entries
+}
+
+// packons and the tackons that are added to every form, the ones used with one case are left out, ex: mecum
+fn compounds() -> Vec<Compound> {
+    let mut attachments: Vec<Attachment> = get_latin_packons();
+    attachments.extend(
+        get_latin_not_packons()
+            .into_iter()
+            .filter(|attachment| attachment.pos == PartOfSpeech::Pronoun),
+    );
+
+    attachments
+        .into_iter()
+        .filter(|attachment| {
+            !attachment
+                .senses
+                .iter()
+                .any(|sense| sense.contains("ABL") || sense.contains("GEN"))
+        })
+        .filter_map(|attachment| {
+            let form_array: Vec<&str> = attachment.form.split_whitespace().collect();
+            if form_array.len() < 3 {
+                return None;
+            }
+
+            Some(Compound {
+                suffix: attachment.orth.to_string(),
+                num_type_1: form_array[0].parse::<i8>().unwrap_or(0),
+                num_type_2: form_array[1].parse::<i8>().unwrap_or(0),
+                kind: Pronoun::dict_key_to_pronoun(form_array[2]),
+                senses: attachment.senses.to_vec(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,599 @@
+use crate::dictionary_structures::dictionary_keys::{
+    Mood, Number, PartOfSpeech, Tense, Verb, Voice,
+};
+use crate::dictionary_structures::dictionary_values::{Form, Inflection, LatinWordInfo};
+use crate::paradigms::inflections_for;
+use crate::translators::latin_to_english::trace::Trace;
+use crate::translators::latin_to_english::translate_latin_to_english;
+use crate::utils::number_with_ending;
+use serde::Serialize;
+
+// the rows of a synopsis, each has an active and a passive form
+const FINITE_ROWS: [(Mood, Tense); 12] = [
+    (Mood::Indicative, Tense::Present),
+    (Mood::Indicative, Tense::Imperfect),
+    (Mood::Indicative, Tense::Future),
+    (Mood::Indicative, Tense::Perfect),
+    (Mood::Indicative, Tense::Pluperfect),
+    (Mood::Indicative, Tense::FuturePerfect),
+    (Mood::Subjunctive, Tense::Present),
+    (Mood::Subjunctive, Tense::Imperfect),
+    (Mood::Subjunctive, Tense::Perfect),
+    (Mood::Subjunctive, Tense::Pluperfect),
+    (Mood::Imperative, Tense::Present),
+    (Mood::Imperative, Tense::Future),
+];
+
+const NON_FINITE_ROWS: [(Mood, Tense); 6] = [
+    (Mood::Infinitive, Tense::Present),
+    (Mood::Infinitive, Tense::Perfect),
+    (Mood::Infinitive, Tense::Future),
+    (Mood::Participle, Tense::Present),
+    (Mood::Participle, Tense::Perfect),
+    (Mood::Participle, Tense::Future),
+];
+
+// the forms of sum that make the perfect passive, by person: singular, plural
+const ESSE: [(Mood, Tense, [&str; 3], [&str; 3]); 5] = [
+    (
+        Mood::Indicative,
+        Tense::Perfect,
+        ["sum", "es", "est"],
+        ["sumus", "estis", "sunt"],
+    ),
+    (
+        Mood::Indicative,
+        Tense::Pluperfect,
+        ["eram", "eras", "erat"],
+        ["eramus", "eratis", "erant"],
+    ),
+    (
+        Mood::Indicative,
+        Tense::FuturePerfect,
+        ["ero", "eris", "erit"],
+        ["erimus", "eritis", "erunt"],
+    ),
+    (
+        Mood::Subjunctive,
+        Tense::Perfect,
+        ["sim", "sis", "sit"],
+        ["simus", "sitis", "sint"],
+    ),
+    (
+        Mood::Subjunctive,
+        Tense::Pluperfect,
+        ["essem", "esses", "esset"],
+        ["essemus", "essetis", "essent"],
+    ),
+];
+
+/**
+ * One cell of a synopsis, a cell can have more than one form, ex: amaris/amare
+ */
+#[derive(Debug, Clone)]
+pub struct SynopsisForm {
+    pub tense: Tense,
+    pub mood: Mood,
+    pub voice: Voice,
+    pub forms: Vec<String>,
+}
+
+impl Serialize for SynopsisForm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut map = serde_json::Map::new();
+        map.insert(
+            "tense".to_string(),
+            serde_json::Value::String(self.tense.as_str().to_string()),
+        );
+        map.insert(
+            "mood".to_string(),
+            serde_json::Value::String(self.mood.as_str().to_string()),
+        );
+        map.insert(
+            "voice".to_string(),
+            serde_json::Value::String(self.voice.as_str().to_string()),
+        );
+        map.insert(
+            "forms".to_string(),
+            serde_json::Value::Array(
+                self.forms
+                    .iter()
+                    .map(|form| serde_json::Value::String(form.to_string()))
+                    .collect(),
+            ),
+        );
+        serde_json::Value::Object(map).serialize(serializer)
+    }
+}
+
+/**
+ * The synopsis of one dictionary entry the verb can come from.
+ * Entries that can not be conjugated have an error instead of forms.
+ */
+#[derive(Debug, Serialize)]
+pub struct Synopsis {
+    pub id: i32,
+    pub parts: Vec<String>,
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forms: Option<Vec<SynopsisForm>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WordSynopses {
+    pub word: String,
+    pub person: i8,
+    pub number: String,
+    pub entries: Vec<Synopsis>,
+}
+
+impl WordSynopses {
+    pub fn display(&self) {
+        println!(
+            "{}: {} person {}",
+            self.word,
+            number_with_ending(self.person),
+            self.number
+        );
+
+        if self.entries.is_empty() {
+            println!("No verbs found");
+        }
+
+        for entry in &self.entries {
+            println!();
+            if entry.kind == Verb::Unknown.as_str() {
+                println!("{}", entry.parts.join(", "));
+            } else {
+                println!("{} ({})", entry.parts.join(", "), entry.kind);
+            }
+
+            let forms = match (&entry.forms, &entry.error) {
+                (Some(forms), _) => forms,
+                (None, Some(error)) => {
+                    println!("{}", error);
+                    continue;
+                }
+                (None, None) => continue,
+            };
+
+            let cell = |mood: Mood, tense: Tense, voice: Voice| {
+                forms
+                    .iter()
+                    .find(|form| form.mood == mood && form.tense == tense && form.voice == voice)
+                    .map(|form| {
+                        if form.forms.is_empty() {
+                            "---".to_string()
+                        } else {
+                            form.forms.join("/")
+                        }
+                    })
+            };
+
+            let width = forms
+                .iter()
+                .map(|form| form.forms.join("/").len())
+                .max()
+                .unwrap_or(0)
+                .max(7)
+                + 2;
+
+            println!(
+                "{}{:<width$}passive",
+                " ".repeat(18),
+                "active",
+                width = width
+            );
+
+            let mut last_mood = Mood::Unknown;
+            for (mood, tense) in FINITE_ROWS.iter().chain(NON_FINITE_ROWS.iter()) {
+                let active = cell(*mood, *tense, Voice::Active);
+                let passive = cell(*mood, *tense, Voice::Passive);
+                if active.is_none() && passive.is_none() {
+                    continue;
+                }
+
+                if *mood != last_mood {
+                    println!("{}", mood.as_str());
+                    last_mood = *mood;
+                }
+
+                println!(
+                    "  {:<16}{:<width$}{}",
+                    tense.as_str(),
+                    active.unwrap_or_default(),
+                    passive.unwrap_or_default(),
+                    width = width
+                );
+            }
+        }
+        println!();
+    }
+}
+
+/**
+ * Finds the verbs a latin word comes from, and conjugates each in every tense, mood and voice
+ * for one person and number, with its infinitives and participles.
+ * When only_existing is set, the forms that deponent and defective verbs lack are left out.
+ */
+pub fn find_synopses(
+    latin_word: &str,
+    person: i8,
+    number: Number,
+    only_existing: bool,
+    tricks: bool,
+) -> WordSynopses {
+    let definitions = translate_latin_to_english(latin_word, tricks, &mut Trace::new(false));
+    let mut entries: Vec<Synopsis> = Vec::new();
+
+    for definition in definitions {
+        let word = definition.word;
+        if word.pos != PartOfSpeech::Verb || entries.iter().any(|entry| entry.id == word.id) {
+            continue;
+        }
+
+        let (forms, error) = match synopsis(&word, person, number, only_existing) {
+            Ok(forms) => (Some(forms), None),
+            Err(error) => (None, Some(error)),
+        };
+
+        entries.push(Synopsis {
+            id: word.id,
+            parts: word
+                .principle_parts()
+                .unwrap_or_else(|_| vec![word.orth.to_string()]),
+            kind: verb_kind(&word).as_str().to_string(),
+            forms,
+            error,
+        });
+    }
+
+    WordSynopses {
+        word: latin_word.to_string(),
+        person,
+        number: number.as_str().to_string(),
+        entries,
+    }
+}
+
+/**
+ * Conjugates a verb from its dictionary stems, ex: am, am, amav, amat
+ */
+pub fn synopsis(
+    word: &LatinWordInfo,
+    person: i8,
+    number: Number,
+    only_existing: bool,
+) -> Result<Vec<SynopsisForm>, String> {
+    let (num_type_1, num_type_2) = match &word.n {
+        Some(n) if n.len() >= 2 => (n[0].get_n_value_1(), n[1].get_n_value_2()),
+        _ => return Err(format!("{} has no conjugation", word.orth)),
+    };
+
+    // 8 is only additional forms of a verb, 9 are abbreviations and undeclined verbs
+    if !(1..=7).contains(&num_type_1) || word.parts.len() < 4 {
+        return Err(format!(
+            "No synopsis for a verb of conjugation {} {}",
+            num_type_1, num_type_2
+        ));
+    }
+
+    let kind = verb_kind(word);
+    let mut verb_inflections = inflections_for(PartOfSpeech::Verb, num_type_1, &[num_type_2]);
+    verb_inflections.extend(inflections_for(
+        PartOfSpeech::Participle,
+        num_type_1,
+        &[num_type_2],
+    ));
+
+    let mut forms: Vec<SynopsisForm> = Vec::new();
+    for (mood, tense) in FINITE_ROWS.iter().chain(NON_FINITE_ROWS.iter()) {
+        for voice in [Voice::Active, Voice::Passive] {
+            if only_existing && lacks_form(kind, *mood, *tense, voice, person, number) {
+                continue;
+            }
+
+            let mut form = SynopsisForm {
+                tense: *tense,
+                mood: *mood,
+                voice,
+                forms: Vec::new(),
+            };
+
+            for inflection in &verb_inflections {
+                if !inflection_matches(inflection, *mood, *tense, voice, person, number) {
+                    continue;
+                }
+
+                let ending = inflection.ending.split_whitespace().next().unwrap_or("");
+                let stem_key = stem_key(
+                    num_type_1, num_type_2, inflection, *mood, *tense, voice, ending,
+                );
+                if let Some(stem) = stem(word, stem_key) {
+                    add_form(&mut form, format!("{}{}", stem, ending));
+                }
+            }
+
+            // the perfect passive and some infinitives are built with sum, ex: amatus est, amatum iri
+            if let Some(participle) = stem(word, 4) {
+                if let Some(compound) =
+                    compound_form(participle, *mood, *tense, voice, person, number)
+                {
+                    add_form(&mut form, compound);
+                }
+            }
+
+            if only_existing && form.forms.is_empty() {
+                continue;
+            }
+
+            forms.push(form);
+        }
+    }
+
+    Ok(forms)
+}
+
+/**
+ * Every simple form of a verb, including the declined participles, in one pass over its inflections.
+ * Forms the verb does not have are left out, ex: the active of a deponent
+ */
+pub fn conjugate(word: &LatinWordInfo) -> Vec<String> {
+    let (num_type_1, num_type_2) = match &word.n {
+        Some(n) if n.len() >= 2 => (n[0].get_n_value_1(), n[1].get_n_value_2()),
+        _ => return Vec::new(),
+    };
+    if !(1..=7).contains(&num_type_1) || word.parts.len() < 4 {
+        return Vec::new();
+    }
+
+    let kind = verb_kind(word);
+    let mut verb_inflections = inflections_for(PartOfSpeech::Verb, num_type_1, &[num_type_2]);
+    verb_inflections.extend(inflections_for(
+        PartOfSpeech::Participle,
+        num_type_1,
+        &[num_type_2],
+    ));
+
+    let mut forms: Vec<String> = Vec::new();
+    for inflection in &verb_inflections {
+        let form = inflection.form.as_str();
+        let form_array: Vec<&str> = form.split_whitespace().collect();
+        let (mood, tense, voice, person, number) = match (inflection.pos, form_array.len()) {
+            (PartOfSpeech::Verb, 5) => (
+                Mood::dict_key_to_mood(form_array[2]),
+                Tense::dict_key_to_tense(form_array[0]),
+                Voice::dict_key_to_voice(form_array[1]),
+                form_array[3].parse::<i8>().unwrap_or(0),
+                Number::dict_key_to_number(form_array[4]),
+            ),
+            (PartOfSpeech::Participle, 6) => (
+                Mood::Participle,
+                Tense::dict_key_to_tense(form_array[3]),
+                Voice::dict_key_to_voice(form_array[4]),
+                0,
+                Number::dict_key_to_number(form_array[1]),
+            ),
+            _ => continue,
+        };
+        if lacks_form(kind, mood, tense, voice, person, number) {
+            continue;
+        }
+
+        let ending = inflection.ending.split_whitespace().next().unwrap_or("");
+        let stem_key = stem_key(
+            num_type_1, num_type_2, inflection, mood, tense, voice, ending,
+        );
+        if let Some(stem) = stem(word, stem_key) {
+            let new_form = format!("{}{}", stem, ending).to_lowercase();
+            if !forms.contains(&new_form) {
+                forms.push(new_form);
+            }
+        }
+    }
+
+    forms
+}
+
+fn verb_kind(word: &LatinWordInfo) -> Verb {
+    match &word.form {
+        Form::LongForm(form) => form.verb.unwrap_or(Verb::Unknown),
+        Form::StrForm(form) => match form.split_whitespace().nth(2) {
+            Some(kind) => Verb::dict_key_to_verb(kind),
+            None => Verb::Unknown,
+        },
+    }
+}
+
+fn stem(word: &LatinWordInfo, stem_key: usize) -> Option<&str> {
+    word.parts
+        .get(stem_key - 1)
+        .map(|part| part.as_str())
+        .filter(|part| *part != "zzz")
+}
+
+fn add_form(form: &mut SynopsisForm, new_form: String) {
+    if !form.forms.contains(&new_form) {
+        form.forms.push(new_form);
+    }
+}
+
+// PRES  ACTIVE  IND  3 S for verbs, NOM S X PRES ACTIVE  PPL for participles
+fn inflection_matches(
+    inflection: &Inflection,
+    mood: Mood,
+    tense: Tense,
+    voice: Voice,
+    person: i8,
+    number: Number,
+) -> bool {
+    let form = inflection.form.as_str();
+    let form_array: Vec<&str> = form.split_whitespace().collect();
+
+    match inflection.pos {
+        PartOfSpeech::Verb if form_array.len() == 5 => {
+            let inflection_mood = Mood::dict_key_to_mood(form_array[2]);
+            if inflection_mood != mood
+                || Tense::dict_key_to_tense(form_array[0]) != tense
+                || Voice::dict_key_to_voice(form_array[1]) != voice
+            {
+                return false;
+            }
+
+            // infinitives have no person or number
+            inflection_mood == Mood::Infinitive
+                || (form_array[3].parse::<i8>().unwrap_or(0) == person
+                    && Number::dict_key_to_number(form_array[4]) == number)
+        }
+        PartOfSpeech::Participle if form_array.len() == 6 => {
+            mood == Mood::Participle
+                && form_array[0] == "NOM"
+                && form_array[1] == "S"
+                && ["M", "C", "X"].contains(&form_array[2])
+                && Tense::dict_key_to_tense(form_array[3]) == tense
+                && Voice::dict_key_to_voice(form_array[4]) == voice
+        }
+        _ => false,
+    }
+}
+
+/**
+ * The stem an ending goes on, the data does not keep it for verbs.
+ * The perfect is built on the 3rd stem and the perfect passive and future active participles on the 4th,
+ * the present system is built on the 1st stem, apart from irregular verbs, ex: sum, es; eo, is; volo, velle
+ */
+fn stem_key(
+    num_type_1: i8,
+    num_type_2: i8,
+    inflection: &Inflection,
+    mood: Mood,
+    tense: Tense,
+    voice: Voice,
+    ending: &str,
+) -> usize {
+    let is_perfect_system =
+        [Tense::Perfect, Tense::Pluperfect, Tense::FuturePerfect].contains(&tense);
+    let (inflection_num_type_1, inflection_num_type_2) = match &inflection.n {
+        Some(n) if n.len() >= 2 => (n[0].get_n_value_1(), n[1].get_n_value_2()),
+        _ => (0, 0),
+    };
+
+    if mood == Mood::Participle {
+        if (tense == Tense::Perfect && voice == Voice::Passive)
+            || (tense == Tense::Future && voice == Voice::Active)
+        {
+            return 4;
+        }
+    } else if is_perfect_system && voice == Voice::Active && inflection_num_type_1 != 7 {
+        // defective verbs have their own perfect, ex: ait
+        return 3;
+    }
+
+    let starts_with_any = |letters: &[char]| ending.starts_with(letters);
+    match (num_type_1, num_type_2) {
+        // sum, es, est; possum, potes, possem
+        (5, _)
+            if inflection_num_type_2 == 2
+                || mood == Mood::Participle
+                || starts_with_any(&['u', 'i']) =>
+        {
+            1
+        }
+        (5, _) => 2,
+        // eo, eunt, eam; is, it, ibam, ire
+        (6, 1) if starts_with_any(&['o', 'u', 'a']) => 1,
+        (6, 1) => 2,
+        // volo, volebam; velim, vellem, velle
+        (6, 2) if mood == Mood::Subjunctive || mood == Mood::Infinitive => 2,
+        // aio, aiunt; ais, ait
+        (7, 1) if starts_with_any(&['o', 'u', 'e']) => 1,
+        (7, 1) => 2,
+        // edim; es, est, esse
+        (7, 3) if starts_with_any(&['i']) => 1,
+        (7, 3) => 2,
+        // fierem
+        (3, 3) if ending.starts_with("er") => 1,
+        // capis, capere, cape; fis, fieri; but capio, capiunt, capiebam, audibam
+        (3, _)
+            if inflection_num_type_2 != 0
+                && (ending.is_empty() || starts_with_any(&['i', 'e'])) =>
+        {
+            2
+        }
+        _ => 1,
+    }
+}
+
+fn compound_form(
+    participle: &str,
+    mood: Mood,
+    tense: Tense,
+    voice: Voice,
+    person: i8,
+    number: Number,
+) -> Option<String> {
+    match (mood, tense, voice) {
+        (Mood::Infinitive, Tense::Perfect, Voice::Passive) => {
+            Some(format!("{}us esse", participle))
+        }
+        (Mood::Infinitive, Tense::Future, Voice::Active) => {
+            Some(format!("{}urus esse", participle))
+        }
+        (Mood::Infinitive, Tense::Future, Voice::Passive) => Some(format!("{}um iri", participle)),
+        (_, _, Voice::Passive) => {
+            let (_, _, singular, plural) = ESSE
+                .iter()
+                .find(|(esse_mood, esse_tense, _, _)| *esse_mood == mood && *esse_tense == tense)?;
+            let person_index = (1..=3).contains(&person).then(|| person as usize - 1)?;
+
+            match number {
+                Number::Singular => Some(format!("{}us {}", participle, singular[person_index])),
+                Number::Plural => Some(format!("{}i {}", participle, plural[person_index])),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/**
+ * The forms a verb does not have even though they can be built from its stems.
+ * ex: deponents have no active, except for their participles and future infinitive
+ */
+fn lacks_form(
+    kind: Verb,
+    mood: Mood,
+    tense: Tense,
+    voice: Voice,
+    person: i8,
+    number: Number,
+) -> bool {
+    let is_finite = mood != Mood::Infinitive && mood != Mood::Participle;
+    let is_perfect_system =
+        [Tense::Perfect, Tense::Pluperfect, Tense::FuturePerfect].contains(&tense);
+
+    match kind {
+        Verb::Deponent => {
+            (voice == Voice::Active
+                && mood != Mood::Participle
+                && !(mood == Mood::Infinitive && tense == Tense::Future))
+                || (voice == Voice::Passive && mood == Mood::Infinitive && tense == Tense::Future)
+        }
+        // audeo, audere, ausus sum: active in the present, passive in the perfect
+        Verb::SemiDeponent => {
+            mood != Mood::Participle
+                && ((is_perfect_system && voice == Voice::Active)
+                    || (!is_perfect_system && voice == Voice::Passive))
+        }
+        // sum has no passive
+        Verb::ToBe | Verb::ToBeing => voice == Voice::Passive,
+        // oportet, only the 3rd person singular
+        Verb::Impersonal => is_finite && (person != 3 || number != Number::Singular),
+        _ => false,
+    }
+}
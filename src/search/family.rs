@@ -0,0 +1,371 @@
+use crate::dictionary_structures::dictionary_values::{LatinWordInfo, Modifier, Stem};
+use crate::search::find_entries;
+use crate::translators::latin_to_english::utils::{
+    pos_matches, prefix_rules, suffix_rules, PrefixRule, SuffixRule,
+};
+use crate::utils::data::{get_latin_dictionary, get_latin_stems};
+use serde::Serialize;
+use std::collections::HashMap;
+
+// shorter stems are part of too many unrelated words, ex: eo, is, it
+const MIN_ROOT_LENGTH: usize = 3;
+
+/**
+ * A word related to the searched word, with the prefix or suffix that makes it
+ */
+#[derive(Debug, Serialize)]
+pub struct FamilyMember {
+    pub id: i32,
+    pub lemma: String,
+    pub parts: Vec<String>,
+    pub pos: String,
+    pub senses: Vec<String>,
+    pub affixes: Vec<Modifier>,
+}
+
+/**
+ * The relatives that come from the same word in the same way, ex: prefix + duco
+ */
+#[derive(Debug, Serialize)]
+pub struct FamilyGroup {
+    pub path: String,
+    pub from: String,
+    pub members: Vec<FamilyMember>,
+    // the word first, then its compounds, each in the order of derivation
+    #[serde(skip)]
+    order: (usize, u8),
+}
+
+#[derive(Debug, Serialize)]
+pub struct WordFamily {
+    pub word: String,
+    pub lemmas: Vec<String>,
+    pub roots: Vec<String>,
+    pub groups: Vec<FamilyGroup>,
+}
+
+impl WordFamily {
+    pub fn display(&self) {
+        println!(
+            "{} ({}), roots: {}",
+            self.word,
+            self.lemmas.join(", "),
+            self.roots.join(", ")
+        );
+
+        if self.groups.is_empty() {
+            println!("No relatives found");
+        }
+
+        for group in &self.groups {
+            println!();
+            println!("{}:", group.path);
+            for member in &group.members {
+                println!("  {} ({})", member.parts.join(", "), member.pos);
+                for affix in &member.affixes {
+                    let affix_orth = match affix.modifier.as_str().as_str() {
+                        "prefix" => format!("{}-", affix.orth),
+                        _ => format!("-{}", affix.orth),
+                    };
+                    println!("    {}: {}", affix_orth, affix.senses.join(" "));
+                }
+                println!("    {}", member.senses.join(" "));
+            }
+        }
+        println!();
+    }
+}
+
+// a word of the family and the stems its relatives are built on
+struct Root<'a> {
+    word: &'a LatinWordInfo,
+    lemma: String,
+}
+
+// how a stem is built from the stem of a root
+enum Derivation<'a> {
+    SameStem,
+    Prefix(&'a PrefixRule),
+    Suffix(&'a SuffixRule),
+    PrefixAndSuffix(&'a PrefixRule, &'a SuffixRule),
+}
+
+impl Derivation<'_> {
+    fn order(&self) -> u8 {
+        match self {
+            Derivation::SameStem => 0,
+            Derivation::Prefix(_) => 1,
+            Derivation::Suffix(_) => 2,
+            Derivation::PrefixAndSuffix(_, _) => 3,
+        }
+    }
+
+    fn path(&self, from: &str) -> String {
+        match self {
+            Derivation::SameStem => format!("same stem as {}", from),
+            Derivation::Prefix(_) => format!("prefix + {}", from),
+            Derivation::Suffix(_) => format!("{} + suffix", from),
+            Derivation::PrefixAndSuffix(_, _) => format!("prefix + {} + suffix", from),
+        }
+    }
+
+    fn affixes(&self) -> Vec<Modifier> {
+        match self {
+            Derivation::SameStem => Vec::new(),
+            Derivation::Prefix(prefix) => vec![prefix.modifier.clone()],
+            Derivation::Suffix(suffix) => vec![suffix.modifier.clone()],
+            Derivation::PrefixAndSuffix(prefix, suffix) => {
+                vec![prefix.modifier.clone(), suffix.modifier.clone()]
+            }
+        }
+    }
+}
+
+/**
+ * Finds the entries that share a root with a word, ex: duco -> dux, ductor, adduco, conduco, educatio
+ * Relatives are found in the stem table directly, after removing a prefix or suffix, or both.
+ * Compounds of the word are searched once more for their own suffixes, ex: educo -> educatio
+ */
+pub fn find_family(latin_word: &str, tricks: bool) -> Result<WordFamily, String> {
+    let latin_dictionary = get_latin_dictionary();
+    let words_by_id: HashMap<i32, &LatinWordInfo> = latin_dictionary
+        .iter()
+        .map(|word| (word.id, word))
+        .collect();
+
+    let heads: Vec<Root> = find_entries(latin_word, tricks, &words_by_id)
+        .into_iter()
+        .map(|word| Root {
+            word,
+            lemma: word.lemma(),
+        })
+        .collect();
+    if heads.is_empty() {
+        return Err(format!("{} is not in the dictionary", latin_word));
+    }
+
+    let prefix_rules = prefix_rules();
+    let suffix_rules = suffix_rules();
+    let stems = get_latin_stems();
+
+    let mut groups: Vec<FamilyGroup> = Vec::new();
+    let mut found: Vec<i32> = heads.iter().map(|head| head.word.id).collect();
+
+    // the relatives of the word, then the words built on the compounds
+    let mut compounds: Vec<Root> = Vec::new();
+    for root in &heads {
+        for (word, derivation) in
+            relatives(root, &stems, &words_by_id, &prefix_rules, &suffix_rules)
+        {
+            if found.contains(&word.id) {
+                continue;
+            }
+            found.push(word.id);
+
+            if matches!(derivation, Derivation::Prefix(_)) {
+                compounds.push(Root {
+                    word,
+                    lemma: word.lemma(),
+                });
+            }
+            add_member(&mut groups, 0, &root.lemma, &derivation, word);
+        }
+    }
+
+    for (index, root) in compounds.iter().enumerate() {
+        for (word, derivation) in
+            relatives(root, &stems, &words_by_id, &prefix_rules, &suffix_rules)
+        {
+            if found.contains(&word.id) || !matches!(derivation, Derivation::Suffix(_)) {
+                continue;
+            }
+            found.push(word.id);
+            add_member(&mut groups, index + 1, &root.lemma, &derivation, word);
+        }
+    }
+    groups.sort_by_key(|group| group.order);
+
+    let mut lemmas: Vec<String> = Vec::new();
+    let mut roots: Vec<String> = Vec::new();
+    for head in &heads {
+        if !lemmas.contains(&head.lemma) {
+            lemmas.push(head.lemma.clone());
+        }
+        for stem in root_stems(head.word) {
+            if !roots.contains(&stem) {
+                roots.push(stem);
+            }
+        }
+    }
+
+    Ok(WordFamily {
+        word: latin_word.to_string(),
+        lemmas,
+        roots,
+        groups,
+    })
+}
+
+// the entries with a stem that is built from a stem of the root
+fn relatives<'a>(
+    root: &Root,
+    stems: &[Stem],
+    words_by_id: &HashMap<i32, &'a LatinWordInfo>,
+    prefix_rules: &'a [PrefixRule],
+    suffix_rules: &'a [SuffixRule],
+) -> Vec<(&'a LatinWordInfo, Derivation<'a>)> {
+    // stem -> the stem key it is, ex: duct -> 4
+    let mut root_stems: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, part) in root.word.parts.iter().enumerate() {
+        if part.len() >= MIN_ROOT_LENGTH && part != "zzz" {
+            root_stems
+                .entry(part.to_string())
+                .or_default()
+                .push(index + 1);
+        }
+    }
+
+    let mut output: Vec<(&'a LatinWordInfo, Derivation<'a>)> = Vec::new();
+    for stem in stems {
+        if stem.wid == root.word.id || output.iter().any(|(word, _)| word.id == stem.wid) {
+            continue;
+        }
+        let word = match words_by_id.get(&stem.wid) {
+            Some(word) => *word,
+            None => continue,
+        };
+
+        if let Some(derivation) = derive(
+            &stem.orth,
+            word,
+            root,
+            &root_stems,
+            prefix_rules,
+            suffix_rules,
+        ) {
+            output.push((word, derivation));
+        }
+    }
+
+    output
+}
+
+// how a stem of a word comes from a stem of the root, if it does
+fn derive<'a>(
+    stem: &str,
+    word: &LatinWordInfo,
+    root: &Root,
+    root_stems: &HashMap<String, Vec<usize>>,
+    prefix_rules: &'a [PrefixRule],
+    suffix_rules: &'a [SuffixRule],
+) -> Option<Derivation<'a>> {
+    if root_stems.contains_key(stem) {
+        return Some(Derivation::SameStem);
+    }
+
+    // the stem itself, then the stem without each prefix it can have
+    let mut remainders: Vec<(Option<&'a PrefixRule>, &str)> = vec![(None, stem)];
+    for prefix in prefix_rules {
+        // a prefix does not change the part of speech, ex: duco -> adduco
+        if !pos_matches(prefix.pos, word.pos) {
+            continue;
+        }
+        if let Some(remainder) = stem.strip_prefix(prefix.affix.as_str()) {
+            if prefix
+                .connect
+                .is_none_or(|connect| remainder.starts_with(connect))
+            {
+                remainders.push((Some(prefix), remainder));
+            }
+        }
+    }
+
+    for (prefix, remainder) in &remainders {
+        // a prefix keeps the conjugation or declension, ex: con- + fut (sum) is not confuto
+        if let Some(prefix) = prefix {
+            if root_stems.contains_key(*remainder)
+                && word.pos == root.word.pos
+                && num_type_1(word) == num_type_1(root.word)
+            {
+                return Some(Derivation::Prefix(prefix));
+            }
+        }
+
+        for suffix in suffix_rules {
+            let base = match remainder.strip_suffix(suffix.affix.as_str()) {
+                Some(base) => base,
+                None => continue,
+            };
+            let keys = match root_stems.get(base) {
+                Some(keys) => keys,
+                None => continue,
+            };
+
+            // the suffix goes on the stem the rule asks for, ex: -or on the 4th stem of a verb
+            let base_key_matches = keys
+                .iter()
+                .any(|key| suffix.base_key == *key || (suffix.base_key == 0 && *key <= 2));
+            if !base_key_matches
+                || !pos_matches(suffix.base_pos, root.word.pos)
+                || !pos_matches(Some(suffix.derived_pos), word.pos)
+                || suffix
+                    .connect
+                    .is_some_and(|connect| !base.ends_with(connect))
+            {
+                continue;
+            }
+
+            return Some(match prefix {
+                Some(prefix) => Derivation::PrefixAndSuffix(prefix, suffix),
+                None => Derivation::Suffix(suffix),
+            });
+        }
+    }
+
+    None
+}
+
+fn add_member(
+    groups: &mut Vec<FamilyGroup>,
+    root_index: usize,
+    from: &str,
+    derivation: &Derivation,
+    word: &LatinWordInfo,
+) {
+    let path = derivation.path(from);
+    let member = FamilyMember {
+        id: word.id,
+        lemma: word.lemma(),
+        parts: word
+            .principle_parts()
+            .unwrap_or_else(|_| vec![word.orth.to_string()]),
+        pos: word.pos.as_str().to_string(),
+        senses: word.senses.clone(),
+        affixes: derivation.affixes(),
+    };
+
+    match groups.iter_mut().find(|group| group.path == path) {
+        Some(group) => group.members.push(member),
+        None => groups.push(FamilyGroup {
+            path,
+            from: from.to_string(),
+            members: vec![member],
+            order: (root_index, derivation.order()),
+        }),
+    }
+}
+
+fn num_type_1(word: &LatinWordInfo) -> Option<i8> {
+    word.n
+        .as_ref()
+        .and_then(|n| n.first())
+        .map(|n| n.get_n_value_1())
+}
+
+fn root_stems(word: &LatinWordInfo) -> Vec<String> {
+    word.parts
+        .iter()
+        .filter(|part| part.len() >= MIN_ROOT_LENGTH && *part != "zzz")
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,326 @@
+pub mod family;
+pub mod query;
+pub mod synonyms;
+
+use self::query::{parse_query, split_words, Query};
+use crate::dictionary_structures::dictionary_keys::{Age, Area, Frequency, PartOfSpeech};
+use crate::dictionary_structures::dictionary_values::{Form, LatinWordInfo};
+use crate::translators::latin_to_english::trace::Trace;
+use crate::translators::latin_to_english::translate_latin_to_english;
+use crate::translators::Structure;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+// keeps phrases from matching across glosses, ex: "ship war" in ship; war
+const GLOSS_GAP: usize = 10;
+
+/**
+ * The part of speech, age and area a word must have, nothing means any
+ */
+#[derive(Debug, Clone, Default)]
+pub struct WordFilter {
+    pub pos: Option<Vec<PartOfSpeech>>,
+    pub age: Option<Vec<Age>>,
+    pub area: Option<Vec<Area>>,
+}
+
+impl WordFilter {
+    pub fn new() -> WordFilter {
+        WordFilter::default()
+    }
+
+    pub fn set_pos(mut self, pos: Option<Vec<PartOfSpeech>>) -> WordFilter {
+        self.pos = pos;
+        self
+    }
+
+    pub fn set_age(mut self, age: Option<Vec<Age>>) -> WordFilter {
+        self.age = age;
+        self
+    }
+
+    pub fn set_area(mut self, area: Option<Vec<Area>>) -> WordFilter {
+        self.area = area;
+        self
+    }
+
+    pub fn matches(&self, word: &LatinWordInfo) -> bool {
+        self.pos.as_ref().is_none_or(|pos| pos.contains(&word.pos))
+            && self
+                .age
+                .as_ref()
+                .is_none_or(|age| age.contains(&word.info.age))
+            && self
+                .area
+                .as_ref()
+                .is_none_or(|area| area.contains(&word.info.area))
+    }
+}
+
+/**
+ * A word found by a search with how well it matched
+ */
+#[derive(Debug, Serialize)]
+pub struct SearchResult {
+    pub word: LatinWordInfo,
+    pub score: f64,
+}
+
+impl SearchResult {
+    pub fn display(&self) {
+        println!("{} (score {})", self.word.parts.join(" "), self.score);
+        println!("{}", self.word.pos.as_str());
+        match &self.word.form {
+            Form::StrForm(form) => println!("{}", form),
+            Form::LongForm(form) => println!("{}", form.as_clean_str()),
+        }
+        println!("{}", self.word.senses.join(" "));
+        println!();
+    }
+}
+
+/**
+ * An inverted index from the words of the senses to the latin words that use them
+ */
+pub struct SenseIndex<'a> {
+    words: &'a [LatinWordInfo],
+    // word of a sense -> (index of the latin word, positions of the word in its senses)
+    postings: BTreeMap<String, Vec<(usize, Vec<usize>)>>,
+}
+
+impl<'a> SenseIndex<'a> {
+    pub fn new(words: &'a [LatinWordInfo]) -> SenseIndex<'a> {
+        let mut postings: BTreeMap<String, Vec<(usize, Vec<usize>)>> = BTreeMap::new();
+
+        for (index, word) in words.iter().enumerate() {
+            let mut positions: HashMap<String, Vec<usize>> = HashMap::new();
+            let mut position = 0;
+            let extension_senses = word.extension_senses.as_deref().unwrap_or_default();
+            for sense in word.senses.iter().chain(extension_senses) {
+                for gloss in sense.split([',', ';']) {
+                    for sense_word in split_words(gloss) {
+                        positions.entry(sense_word).or_default().push(position);
+                        position += 1;
+                    }
+                    position += GLOSS_GAP;
+                }
+            }
+
+            for (sense_word, positions) in positions {
+                postings
+                    .entry(sense_word)
+                    .or_default()
+                    .push((index, positions));
+            }
+        }
+
+        SenseIndex { words, postings }
+    }
+
+    /**
+     * Finds the words with senses that match the query, best first.
+     * The score of a word is the weight of the terms it matched, rarer terms weigh more, times the frequency of the word.
+     */
+    pub fn search(
+        &self,
+        query: &str,
+        filter: &WordFilter,
+        max: usize,
+    ) -> Result<Vec<SearchResult>, String> {
+        let query = parse_query(query)?;
+        let scores = self.evaluate(&query);
+
+        let mut results: Vec<(usize, f64)> = scores
+            .into_iter()
+            .filter(|(index, _)| filter.matches(&self.words[*index]))
+            .map(|(index, score)| (index, score * frequency_weight(self.words[index].info.freq)))
+            .collect();
+        results.sort_by(|(a_index, a_score), (b_index, b_score)| {
+            b_score.total_cmp(a_score).then(a_index.cmp(b_index))
+        });
+        results.truncate(max);
+
+        Ok(results
+            .into_iter()
+            .map(|(index, score)| {
+                let mut word = self.words[index].clone();
+                word.form
+                    .str_form_to_long_form(word.pos, Structure::LatinWordInfo);
+                word.generate_principle_parts();
+                SearchResult {
+                    word,
+                    score: (score * 100.0).round() / 100.0,
+                }
+            })
+            .collect())
+    }
+
+    // the words that match the query with their scores
+    fn evaluate(&self, query: &Query) -> HashMap<usize, f64> {
+        match query {
+            Query::Term(term) => self.term_scores(term),
+            Query::Prefix(prefix) => {
+                let mut scores: HashMap<usize, f64> = HashMap::new();
+                for term in self
+                    .postings
+                    .range(prefix.to_string()..)
+                    .map(|(term, _)| term)
+                    .take_while(|term| term.starts_with(prefix.as_str()))
+                {
+                    for (index, score) in self.term_scores(term) {
+                        let best = scores.entry(index).or_default();
+                        *best = best.max(score);
+                    }
+                }
+                scores
+            }
+            Query::Phrase(words) => self.phrase_scores(words),
+            Query::And(queries) => {
+                let (excluded, included): (Vec<&Query>, Vec<&Query>) = queries
+                    .iter()
+                    .partition(|query| matches!(query, Query::Not(_)));
+
+                let mut scores: Option<HashMap<usize, f64>> = None;
+                for query in included {
+                    let query_scores = self.evaluate(query);
+                    scores = Some(match scores {
+                        None => query_scores,
+                        Some(scores) => scores
+                            .into_iter()
+                            .filter_map(|(index, score)| {
+                                query_scores.get(&index).map(|other| (index, score + other))
+                            })
+                            .collect(),
+                    });
+                }
+                // only exclusions, ex: NOT ship
+                let mut scores = scores.unwrap_or_else(|| self.all_words());
+
+                for query in excluded {
+                    if let Query::Not(query) = query {
+                        for index in self.evaluate(query).keys() {
+                            scores.remove(index);
+                        }
+                    }
+                }
+                scores
+            }
+            Query::Or(queries) => {
+                let mut scores: HashMap<usize, f64> = HashMap::new();
+                for query in queries {
+                    for (index, score) in self.evaluate(query) {
+                        *scores.entry(index).or_default() += score;
+                    }
+                }
+                scores
+            }
+            Query::Not(query) => {
+                let excluded = self.evaluate(query);
+                self.all_words()
+                    .into_iter()
+                    .filter(|(index, _)| !excluded.contains_key(index))
+                    .collect()
+            }
+        }
+    }
+
+    // tf-idf, a word used more often in the senses scores higher but a word in many entries scores lower
+    fn term_scores(&self, term: &str) -> HashMap<usize, f64> {
+        match self.postings.get(term) {
+            Some(postings) => {
+                let weight = self.term_weight(postings.len());
+                postings
+                    .iter()
+                    .map(|(index, positions)| (*index, (positions.len() as f64).sqrt() * weight))
+                    .collect()
+            }
+            None => HashMap::new(),
+        }
+    }
+
+    fn phrase_scores(&self, words: &[String]) -> HashMap<usize, f64> {
+        let Some(postings) = words
+            .iter()
+            .map(|word| self.postings.get(word))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return HashMap::new();
+        };
+
+        let weight: f64 = postings
+            .iter()
+            .map(|postings| self.term_weight(postings.len()))
+            .sum();
+        let positions_of: Vec<HashMap<usize, HashSet<usize>>> = postings
+            .iter()
+            .map(|postings| {
+                postings
+                    .iter()
+                    .map(|(index, positions)| (*index, positions.iter().copied().collect()))
+                    .collect()
+            })
+            .collect();
+
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+        for (index, starts) in postings[0] {
+            // every word of the phrase follows the one before it
+            let count = starts
+                .iter()
+                .filter(|start| {
+                    positions_of
+                        .iter()
+                        .enumerate()
+                        .skip(1)
+                        .all(|(offset, positions)| {
+                            positions
+                                .get(index)
+                                .is_some_and(|positions| positions.contains(&(*start + offset)))
+                        })
+                })
+                .count();
+            if count > 0 {
+                scores.insert(*index, (count as f64).sqrt() * weight);
+            }
+        }
+        scores
+    }
+
+    fn term_weight(&self, document_count: usize) -> f64 {
+        (self.words.len() as f64 / document_count as f64).ln() + 1.0
+    }
+
+    fn all_words(&self) -> HashMap<usize, f64> {
+        (0..self.words.len()).map(|index| (index, 0.0)).collect()
+    }
+}
+
+/**
+ * The dictionary entries a latin word is a form of, only the ones it is the lemma of if there are any, ex: dux but not duco for dux
+ */
+pub fn find_entries<'a>(
+    latin_word: &str,
+    tricks: bool,
+    words_by_id: &HashMap<i32, &'a LatinWordInfo>,
+) -> Vec<&'a LatinWordInfo> {
+    let mut entries: Vec<&LatinWordInfo> = Vec::new();
+    for definition in translate_latin_to_english(latin_word, tricks, &mut Trace::new(false)) {
+        if let Some(word) = words_by_id.get(&definition.word.id) {
+            if !entries.iter().any(|entry| entry.id == word.id) {
+                entries.push(word);
+            }
+        }
+    }
+
+    if entries.iter().any(|entry| entry.lemma() == latin_word) {
+        entries.retain(|entry| entry.lemma() == latin_word);
+    }
+    entries
+}
+
+// common words rank above rare ones with the same senses
+fn frequency_weight(frequency: Frequency) -> f64 {
+    match frequency.as_number() {
+        number @ 1..=6 => 1.0 + (6 - number) as f64 * 0.1,
+        _ => 0.9,
+    }
+}
@@ -0,0 +1,212 @@
+/**
+ * A parsed search query, ex: ship AND (war OR "merchant ship") NOT sail*
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    Term(String),
+    // sail* -> sail, sailor, sailing
+    Prefix(String),
+    Phrase(Vec<String>),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+    Not(Box<Query>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Prefix(String),
+    Phrase(Vec<String>),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl Token {
+    fn as_str(&self) -> String {
+        match self {
+            Token::Word(word) => word.to_string(),
+            Token::Prefix(prefix) => format!("{}*", prefix),
+            Token::Phrase(words) => format!("\"{}\"", words.join(" ")),
+            Token::And => "AND".to_string(),
+            Token::Or => "OR".to_string(),
+            Token::Not => "NOT".to_string(),
+            Token::Open => "(".to_string(),
+            Token::Close => ")".to_string(),
+        }
+    }
+}
+
+/**
+ * Parses a query where words next to each other must all match, OR matches either side and NOT or - excludes a word.
+ * Quotes match a phrase, a * at the end of a word matches any word starting with it and parentheses group.
+ */
+pub fn parse_query(query: &str) -> Result<Query, String> {
+    let tokens = tokenize(query)?;
+    if tokens.is_empty() {
+        return Err("The search query is empty".to_string());
+    }
+
+    let mut position = 0;
+    let parsed = parse_or(&tokens, &mut position)?;
+    match tokens.get(position) {
+        None => Ok(parsed),
+        Some(Token::Close) => Err("Unmatched ) in the search query".to_string()),
+        Some(token) => Err(format!("Unexpected {} in the search query", token.as_str())),
+    }
+}
+
+/**
+ * The lowercase words of a text, ex: Ship's (war) -> ship, war
+ */
+pub fn split_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        // ship's -> ship
+        .map(|word| word.trim_matches('\''))
+        .map(|word| word.strip_suffix("'s").unwrap_or(word))
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '"' => {
+                let mut phrase = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        closed = true;
+                        break;
+                    }
+                    phrase.push(c);
+                }
+                if !closed {
+                    return Err("Unmatched \" in the search query".to_string());
+                }
+                let words = split_words(&phrase);
+                if !words.is_empty() {
+                    tokens.push(Token::Phrase(words));
+                }
+            }
+            // -sail is the same as NOT sail
+            '-' => tokens.push(Token::Not),
+            c if c.is_whitespace() => {}
+            c => {
+                let mut word = c.to_string();
+                while let Some(next) = chars.peek() {
+                    if next.is_whitespace() || ['(', ')', '"'].contains(next) {
+                        break;
+                    }
+                    word.push(*next);
+                    chars.next();
+                }
+                match word.as_str() {
+                    "AND" => tokens.push(Token::And),
+                    "OR" => tokens.push(Token::Or),
+                    "NOT" => tokens.push(Token::Not),
+                    _ => {
+                        // a * is only allowed at the end of a word, ex: sail*
+                        let prefix = word.strip_suffix('*');
+                        if prefix.unwrap_or(&word).contains('*') {
+                            return Err(format!(
+                                "{} is not a valid wildcard, only a * at the end of a word is allowed",
+                                word
+                            ));
+                        }
+
+                        let words = split_words(&word);
+                        match words.len() {
+                            0 => {}
+                            1 if prefix.is_some() => tokens.push(Token::Prefix(words[0].clone())),
+                            1 => tokens.push(Token::Word(words[0].clone())),
+                            // words joined by punctuation are a phrase, ex: man-of-war
+                            _ => tokens.push(Token::Phrase(words)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn parse_or(tokens: &[Token], position: &mut usize) -> Result<Query, String> {
+    let mut queries = vec![parse_and(tokens, position)?];
+    while tokens.get(*position) == Some(&Token::Or) {
+        *position += 1;
+        queries.push(parse_and(tokens, position)?);
+    }
+
+    if queries.len() == 1 {
+        Ok(queries.remove(0))
+    } else {
+        Ok(Query::Or(queries))
+    }
+}
+
+fn parse_and(tokens: &[Token], position: &mut usize) -> Result<Query, String> {
+    let mut queries = vec![parse_not(tokens, position)?];
+    loop {
+        match tokens.get(*position) {
+            Some(Token::And) => {
+                *position += 1;
+                queries.push(parse_not(tokens, position)?);
+            }
+            // words next to each other must all match
+            Some(Token::Word(_))
+            | Some(Token::Prefix(_))
+            | Some(Token::Phrase(_))
+            | Some(Token::Not)
+            | Some(Token::Open) => {
+                queries.push(parse_not(tokens, position)?);
+            }
+            _ => break,
+        }
+    }
+
+    if queries.len() == 1 {
+        Ok(queries.remove(0))
+    } else {
+        Ok(Query::And(queries))
+    }
+}
+
+fn parse_not(tokens: &[Token], position: &mut usize) -> Result<Query, String> {
+    if tokens.get(*position) == Some(&Token::Not) {
+        *position += 1;
+        return Ok(Query::Not(Box::new(parse_not(tokens, position)?)));
+    }
+    parse_primary(tokens, position)
+}
+
+fn parse_primary(tokens: &[Token], position: &mut usize) -> Result<Query, String> {
+    let token = tokens
+        .get(*position)
+        .ok_or_else(|| "The search query ends too early".to_string())?;
+    *position += 1;
+
+    match token {
+        Token::Word(word) => Ok(Query::Term(word.to_string())),
+        Token::Prefix(prefix) => Ok(Query::Prefix(prefix.to_string())),
+        Token::Phrase(words) => Ok(Query::Phrase(words.clone())),
+        Token::Open => {
+            let query = parse_or(tokens, position)?;
+            if tokens.get(*position) != Some(&Token::Close) {
+                return Err("Unmatched ( in the search query".to_string());
+            }
+            *position += 1;
+            Ok(query)
+        }
+        token => Err(format!("Unexpected {} in the search query", token.as_str())),
+    }
+}
@@ -0,0 +1,190 @@
+use crate::dictionary_structures::dictionary_values::{Form, LatinWordInfo};
+use crate::search::{find_entries, frequency_weight};
+use crate::translators::Structure;
+use crate::utils::data::{get_english_dictionary, get_latin_dictionary};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/**
+ * A latin word with english glosses in common with the searched word
+ */
+#[derive(Debug, Serialize)]
+pub struct Synonym {
+    pub word: LatinWordInfo,
+    pub shared: Vec<String>,
+    pub score: f64,
+    // where the word is used differently than the searched word, ex: age: late
+    pub differences: Vec<String>,
+}
+
+/**
+ * One meaning of the searched word with its english glosses and the latin words that share them
+ */
+#[derive(Debug, Serialize)]
+pub struct SynonymGroup {
+    pub word: LatinWordInfo,
+    pub glosses: Vec<String>,
+    pub synonyms: Vec<Synonym>,
+}
+
+impl SynonymGroup {
+    pub fn display(&self) {
+        println!(
+            "{} ({})",
+            self.word.parts.join(", "),
+            self.word.pos.as_str()
+        );
+        println!(
+            "age: {}, area: {}, geography: {}, frequency: {}",
+            self.word.info.age.as_str(),
+            self.word.info.area.as_str(),
+            self.word.info.geo.as_str(),
+            self.word.info.freq.as_str()
+        );
+        println!("glosses: {}", self.glosses.join(", "));
+
+        if self.synonyms.is_empty() {
+            println!("No synonyms found");
+        }
+
+        for synonym in &self.synonyms {
+            println!();
+            println!(
+                "  {} (score {})",
+                synonym.word.parts.join(", "),
+                synonym.score
+            );
+            match &synonym.word.form {
+                Form::StrForm(form) => println!("  {}", form),
+                Form::LongForm(form) => println!("  {}", form.as_clean_str()),
+            }
+            println!("  {}", synonym.word.senses.join(" "));
+            println!("  shared: {}", synonym.shared.join(", "));
+            if !synonym.differences.is_empty() {
+                println!("  differs by {}", synonym.differences.join(", "));
+            }
+        }
+        println!();
+    }
+}
+
+/**
+ * Finds the latin words that share english glosses with a word, through the english index.
+ * A shared gloss used by fewer latin words weighs more, ex: esteem over have, and common words rank higher.
+ */
+pub fn find_synonyms(
+    latin_word: &str,
+    tricks: bool,
+    max: usize,
+) -> Result<Vec<SynonymGroup>, String> {
+    let latin_dictionary = get_latin_dictionary();
+    let words_by_id: HashMap<i32, &LatinWordInfo> = latin_dictionary
+        .iter()
+        .map(|word| (word.id, word))
+        .collect();
+
+    let heads = find_entries(latin_word, tricks, &words_by_id);
+    if heads.is_empty() {
+        return Err(format!("{} is not in the dictionary", latin_word));
+    }
+
+    // the english index links both ways, latin word -> glosses and gloss -> latin words
+    let english_dictionary = get_english_dictionary();
+    let mut glosses_of: HashMap<i32, Vec<&str>> = HashMap::new();
+    let mut words_of: HashMap<&str, Vec<i32>> = HashMap::new();
+    for english_word in &english_dictionary {
+        let glosses = glosses_of.entry(english_word.wid).or_default();
+        if !glosses.contains(&english_word.orth.as_str()) {
+            glosses.push(&english_word.orth);
+            words_of
+                .entry(&english_word.orth)
+                .or_default()
+                .push(english_word.wid);
+        }
+    }
+    let gloss_weight = |gloss: &str| -> f64 {
+        let word_count = words_of.get(gloss).map_or(1, |words| words.len());
+        (glosses_of.len() as f64 / word_count as f64).ln() + 1.0
+    };
+
+    let mut groups: Vec<SynonymGroup> = Vec::new();
+    for head in heads {
+        let glosses = glosses_of.get(&head.id).cloned().unwrap_or_default();
+
+        // the shared glosses of every word of the same part of speech
+        let mut shared: HashMap<i32, Vec<&str>> = HashMap::new();
+        for gloss in &glosses {
+            for id in &words_of[gloss] {
+                let is_candidate =
+                    *id != head.id && words_by_id.get(id).is_some_and(|word| word.pos == head.pos);
+                if is_candidate {
+                    shared.entry(*id).or_default().push(gloss);
+                }
+            }
+        }
+
+        let mut scored: Vec<(i32, f64, Vec<&str>)> = shared
+            .into_iter()
+            .map(|(id, shared)| {
+                // the weight of the shared glosses out of the weight of all glosses of both words
+                let all: HashSet<&str> = glosses
+                    .iter()
+                    .chain(glosses_of.get(&id).into_iter().flatten())
+                    .copied()
+                    .collect();
+                let overlap = shared.iter().map(|gloss| gloss_weight(gloss)).sum::<f64>()
+                    / all.iter().map(|gloss| gloss_weight(gloss)).sum::<f64>();
+                let score = overlap * frequency_weight(words_by_id[&id].info.freq);
+                (id, score, shared)
+            })
+            .collect();
+        scored.sort_by(|(a_id, a_score, _), (b_id, b_score, _)| {
+            b_score.total_cmp(a_score).then(a_id.cmp(b_id))
+        });
+        scored.truncate(max);
+
+        let synonyms: Vec<Synonym> = scored
+            .into_iter()
+            .map(|(id, score, shared)| {
+                let word = words_by_id[&id];
+                Synonym {
+                    word: display_word(word),
+                    shared: shared.iter().map(|gloss| gloss.to_string()).collect(),
+                    score: (score * 100.0).round() / 100.0,
+                    differences: differences(head, word),
+                }
+            })
+            .collect();
+
+        groups.push(SynonymGroup {
+            word: display_word(head),
+            glosses: glosses.iter().map(|gloss| gloss.to_string()).collect(),
+            synonyms,
+        });
+    }
+
+    Ok(groups)
+}
+
+// the age, area and geography of a synonym that are not the ones of the word, ex: age: late
+fn differences(word: &LatinWordInfo, synonym: &LatinWordInfo) -> Vec<String> {
+    let mut differences: Vec<String> = Vec::new();
+    if synonym.info.age != word.info.age {
+        differences.push(format!("age: {}", synonym.info.age.as_str()));
+    }
+    if synonym.info.area != word.info.area {
+        differences.push(format!("area: {}", synonym.info.area.as_str()));
+    }
+    if synonym.info.geo != word.info.geo {
+        differences.push(format!("geography: {}", synonym.info.geo.as_str()));
+    }
+    differences
+}
+
+fn display_word(word: &LatinWordInfo) -> LatinWordInfo {
+    let mut word = word.clone();
+    word.form
+        .str_form_to_long_form(word.pos, Structure::LatinWordInfo);
+    word.generate_principle_parts();
+    word
+}
@@ -0,0 +1,150 @@
+use crate::dictionary_structures::dictionary_keys::{EnglishFormKind, PartOfSpeech};
+use crate::utils::data::get_english_irregular_forms;
+use serde::{Deserialize, Serialize};
+
+// (suffix, replacement, kind), ex: carried -> carry
+const SUFFIX_RULES: [(&str, &str, &[EnglishFormKind]); 19] = [
+    (
+        "ies",
+        "y",
+        &[EnglishFormKind::Plural, EnglishFormKind::ThirdPerson],
+    ),
+    ("ves", "f", &[EnglishFormKind::Plural]),
+    ("ves", "fe", &[EnglishFormKind::Plural]),
+    (
+        "es",
+        "",
+        &[EnglishFormKind::Plural, EnglishFormKind::ThirdPerson],
+    ),
+    (
+        "s",
+        "",
+        &[EnglishFormKind::Plural, EnglishFormKind::ThirdPerson],
+    ),
+    (
+        "ied",
+        "y",
+        &[EnglishFormKind::Past, EnglishFormKind::PastParticiple],
+    ),
+    (
+        "ed",
+        "",
+        &[EnglishFormKind::Past, EnglishFormKind::PastParticiple],
+    ),
+    (
+        "ed",
+        "e",
+        &[EnglishFormKind::Past, EnglishFormKind::PastParticiple],
+    ),
+    ("ying", "ie", &[EnglishFormKind::PresentParticiple]),
+    ("ing", "", &[EnglishFormKind::PresentParticiple]),
+    ("ing", "e", &[EnglishFormKind::PresentParticiple]),
+    ("ier", "y", &[EnglishFormKind::Comparative]),
+    ("er", "", &[EnglishFormKind::Comparative]),
+    ("er", "e", &[EnglishFormKind::Comparative]),
+    ("iest", "y", &[EnglishFormKind::Superlative]),
+    ("est", "", &[EnglishFormKind::Superlative]),
+    ("est", "e", &[EnglishFormKind::Superlative]),
+    ("ily", "y", &[EnglishFormKind::Adverb]),
+    ("ly", "", &[EnglishFormKind::Adverb]),
+];
+
+// words ending in s that are not plurals, ex: glass, bus, basis
+const NOT_PLURAL_ENDINGS: [&str; 3] = ["ss", "us", "is"];
+
+/**
+ * A headword an english word can come from, ex: ran -> run (past)
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnglishLemma {
+    pub lemma: String,
+    pub kind: EnglishFormKind,
+}
+
+impl EnglishLemma {
+    /**
+     * If a headword with this part of speech can have the form, ex: loved -> love (verb) but not love (noun)
+     */
+    pub fn fits(&self, pos: PartOfSpeech) -> bool {
+        match self.kind {
+            EnglishFormKind::Plural => pos == PartOfSpeech::Noun || pos == PartOfSpeech::Pronoun,
+            // quickly -> quick (adjective)
+            EnglishFormKind::Comparative
+            | EnglishFormKind::Superlative
+            | EnglishFormKind::Adverb => {
+                pos == PartOfSpeech::Adjective || pos == PartOfSpeech::Adverb
+            }
+            _ => pos == PartOfSpeech::Verb,
+        }
+    }
+}
+
+/**
+ * Reduces an english word to the headwords it can come from, irregular forms first and then the suffix rules.
+ * The word itself is not included, ex: loved -> lov, love, children -> child, better -> good, well
+ */
+pub fn lemmatize(word: &str) -> Vec<EnglishLemma> {
+    let word = word.to_lowercase();
+    let mut lemmas: Vec<EnglishLemma> = Vec::new();
+    let mut add = |lemma: String, kind: EnglishFormKind| {
+        if lemma != word
+            && !lemmas
+                .iter()
+                .any(|existing| existing.lemma == lemma && existing.kind == kind)
+        {
+            lemmas.push(EnglishLemma { lemma, kind });
+        }
+    };
+
+    for irregular in get_english_irregular_forms() {
+        if irregular.form == word {
+            add(irregular.lemma, irregular.kind);
+        }
+    }
+
+    for (suffix, replacement, kinds) in SUFFIX_RULES {
+        let stem = match word.strip_suffix(suffix) {
+            Some(stem) if stem.len() > 1 => stem,
+            _ => continue,
+        };
+        if suffix == "s"
+            && NOT_PLURAL_ENDINGS
+                .iter()
+                .any(|ending| word.ends_with(ending))
+        {
+            continue;
+        }
+
+        let mut stems = vec![format!("{}{}", stem, replacement)];
+        // planned -> plan, bigger -> big, only ascii consonants are doubled
+        if replacement.is_empty() && ["ed", "ing", "er", "est"].contains(&suffix) {
+            let mut chars = stem.chars().rev();
+            if let (Some(last), Some(second_last)) = (chars.next(), chars.next()) {
+                if last == second_last && last.is_ascii_alphabetic() && !"aeiou".contains(last) {
+                    stems.push(stem[..stem.len() - 1].to_string());
+                }
+            }
+        }
+
+        for stem in stems {
+            for kind in kinds {
+                add(stem.to_string(), *kind);
+            }
+        }
+    }
+
+    lemmas
+}
+
+/**
+ * The headwords an english word can come from as one kind of form, ex: carried (past participle) -> carry
+ */
+pub fn lemmas_of_kind(word: &str, kinds: &[EnglishFormKind]) -> Vec<String> {
+    let mut lemmas: Vec<String> = Vec::new();
+    for lemma in lemmatize(word) {
+        if kinds.contains(&lemma.kind) && !lemmas.contains(&lemma.lemma) {
+            lemmas.push(lemma.lemma);
+        }
+    }
+    lemmas
+}
@@ -1,5 +1,10 @@
+pub mod lemmatizer;
+pub mod morphology;
+pub mod phrases;
 mod utils;
 
+use self::lemmatizer::{lemmatize, EnglishLemma};
+use self::phrases::PhraseMatch;
 use self::utils::{remove_duplicates, weigh_words};
 use crate::dictionary_structures::dictionary_keys::{
     Age, Area, Frequency, Geography, PartOfSpeech, Source,
@@ -7,6 +12,7 @@ use crate::dictionary_structures::dictionary_keys::{
 use crate::dictionary_structures::dictionary_values::{
     EnglishWordInfo, Form, LatinWordInfo, LongForm,
 };
+use crate::utils::sorting::SortBy;
 use crate::utils::{convert_number_to_roman_numeral, is_all_numbers};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -15,11 +21,30 @@ use std::collections::HashMap;
 pub struct EnglishTranslationInfo {
     pub word: EnglishWordInfo,
     pub translation: LatinWordInfo,
+    // the headword that was found when the word itself is not in the index, ex: ran -> run
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub lemma: Option<EnglishLemma>,
+    // how the phrase was found in the senses, ex: take care -> take care (of)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub phrase_match: Option<PhraseMatch>,
 }
 
 impl EnglishTranslationInfo {
     pub fn new(word: EnglishWordInfo, translation: LatinWordInfo) -> EnglishTranslationInfo {
-        EnglishTranslationInfo { word, translation }
+        EnglishTranslationInfo {
+            word,
+            translation,
+            lemma: None,
+            phrase_match: None,
+        }
+    }
+
+    pub fn set_lemma(&mut self, lemma: Option<EnglishLemma>) {
+        self.lemma = lemma;
+    }
+
+    pub fn set_phrase_match(&mut self, phrase_match: Option<PhraseMatch>) {
+        self.phrase_match = phrase_match;
     }
 }
 
@@ -28,7 +53,7 @@ pub fn translate_english_to_latin(
     latin_dictionary: &Vec<LatinWordInfo>,
     english_word: &str,
     max: usize,
-    sort: bool,
+    sort_by: &[SortBy],
 ) -> Vec<EnglishTranslationInfo> {
     let mut output: Vec<EnglishTranslationInfo> = Vec::new();
 
@@ -75,25 +100,37 @@ pub fn translate_english_to_latin(
         .map(|word| (word.id, word))
         .collect();
 
-    for word in english_dictionary {
-        if word.orth.to_lowercase() == english_word.to_lowercase() {
-            let mut translation =
-                EnglishTranslationInfo::new(EnglishWordInfo::new(), LatinWordInfo::new());
+    // the word as it was written first, then the headwords it can come from, ex: ran, run
+    let mut lookups: Vec<(String, Option<EnglishLemma>)> =
+        vec![(english_word.to_lowercase(), None)];
+    lookups.extend(
+        lemmatize(english_word)
+            .into_iter()
+            .map(|lemma| (lemma.lemma.clone(), Some(lemma))),
+    );
+
+    for (lookup, lemma) in lookups {
+        for word in english_dictionary {
+            if word.orth.to_lowercase() == lookup
+                && lemma.as_ref().is_none_or(|lemma| lemma.fits(word.pos))
+            {
+                let mut translation =
+                    EnglishTranslationInfo::new(EnglishWordInfo::new(), LatinWordInfo::new());
 
-            if let Some(latin_word) = latin_words_map.get(&word.wid) {
-                translation.translation.set_word(latin_word);
-            }
+                if let Some(latin_word) = latin_words_map.get(&word.wid) {
+                    translation.translation.set_word(latin_word);
+                }
 
-            translation.word.set_word(word.clone());
-            output.push(translation);
+                translation.word.set_word(word.clone());
+                translation.set_lemma(lemma.clone());
+                output.push(translation);
+            }
         }
     }
 
     output = remove_duplicates(output);
 
-    if sort {
-        output = weigh_words(output);
-    }
+    output = weigh_words(output, sort_by);
 
     // other words are probably rare or wrong (default 6)
     if output.len() > max {
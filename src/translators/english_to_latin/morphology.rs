@@ -0,0 +1,614 @@
+use crate::dictionary_structures::dictionary_keys::{
+    Declension, EnglishFormKind, Gender, Mood, Number, PartOfSpeech, Tense, Voice,
+};
+use crate::dictionary_structures::dictionary_values::{EnglishWordInfo, LatinWordInfo, LongForm};
+use crate::paradigms::comparison::positive_lemma;
+use crate::paradigms::synopsis::synopsis;
+use crate::paradigms::{decline, word_gender, GENDERS};
+use crate::translators::english_to_latin::lemmatizer::lemmas_of_kind;
+use crate::utils::number_with_ending;
+use serde::Serialize;
+
+// the auxiliaries before the head word: (auxiliaries, tense, voice, mood), only infinitives have no subject
+const VERB_PHRASES: [(&str, Tense, Voice, Mood); 30] = [
+    ("", Tense::Present, Voice::Active, Mood::Indicative),
+    ("do", Tense::Present, Voice::Active, Mood::Indicative),
+    ("am", Tense::Present, Voice::Active, Mood::Indicative),
+    ("am being", Tense::Present, Voice::Passive, Mood::Indicative),
+    ("am", Tense::Present, Voice::Passive, Mood::Indicative),
+    ("was", Tense::Imperfect, Voice::Active, Mood::Indicative),
+    ("used to", Tense::Imperfect, Voice::Active, Mood::Indicative),
+    (
+        "was being",
+        Tense::Imperfect,
+        Voice::Passive,
+        Mood::Indicative,
+    ),
+    ("will", Tense::Future, Voice::Active, Mood::Indicative),
+    ("will be", Tense::Future, Voice::Passive, Mood::Indicative),
+    ("", Tense::Perfect, Voice::Active, Mood::Indicative),
+    ("did", Tense::Perfect, Voice::Active, Mood::Indicative),
+    ("have", Tense::Perfect, Voice::Active, Mood::Indicative),
+    ("was", Tense::Perfect, Voice::Passive, Mood::Indicative),
+    (
+        "have been",
+        Tense::Perfect,
+        Voice::Passive,
+        Mood::Indicative,
+    ),
+    ("had", Tense::Pluperfect, Voice::Active, Mood::Indicative),
+    (
+        "had been",
+        Tense::Pluperfect,
+        Voice::Passive,
+        Mood::Indicative,
+    ),
+    (
+        "will have",
+        Tense::FuturePerfect,
+        Voice::Active,
+        Mood::Indicative,
+    ),
+    (
+        "will have been",
+        Tense::FuturePerfect,
+        Voice::Passive,
+        Mood::Indicative,
+    ),
+    ("may", Tense::Present, Voice::Active, Mood::Subjunctive),
+    ("may be", Tense::Present, Voice::Passive, Mood::Subjunctive),
+    ("would", Tense::Imperfect, Voice::Active, Mood::Subjunctive),
+    (
+        "would be",
+        Tense::Imperfect,
+        Voice::Passive,
+        Mood::Subjunctive,
+    ),
+    ("may have", Tense::Perfect, Voice::Active, Mood::Subjunctive),
+    (
+        "may have been",
+        Tense::Perfect,
+        Voice::Passive,
+        Mood::Subjunctive,
+    ),
+    (
+        "would have",
+        Tense::Pluperfect,
+        Voice::Active,
+        Mood::Subjunctive,
+    ),
+    (
+        "would have been",
+        Tense::Pluperfect,
+        Voice::Passive,
+        Mood::Subjunctive,
+    ),
+    ("to", Tense::Present, Voice::Active, Mood::Infinitive),
+    ("to be", Tense::Present, Voice::Passive, Mood::Infinitive),
+    ("to have", Tense::Perfect, Voice::Active, Mood::Infinitive),
+];
+
+// the other forms without a subject
+const NON_FINITE_PHRASES: [(&str, Tense, Voice, Mood); 7] = [
+    (
+        "to have been",
+        Tense::Perfect,
+        Voice::Passive,
+        Mood::Infinitive,
+    ),
+    (
+        "to be about to",
+        Tense::Future,
+        Voice::Active,
+        Mood::Infinitive,
+    ),
+    ("having", Tense::Perfect, Voice::Passive, Mood::Participle),
+    (
+        "having been",
+        Tense::Perfect,
+        Voice::Passive,
+        Mood::Participle,
+    ),
+    ("about to", Tense::Future, Voice::Active, Mood::Participle),
+    ("", Tense::Present, Voice::Active, Mood::Participle),
+    ("", Tense::Perfect, Voice::Passive, Mood::Participle),
+];
+
+// the prepositions that mark a case: (preposition, case)
+const CASE_PREPOSITIONS: [(&str, Declension); 10] = [
+    ("of", Declension::Genitive),
+    ("to", Declension::Dative),
+    ("for", Declension::Dative),
+    ("by", Declension::Ablative),
+    ("with", Declension::Ablative),
+    ("from", Declension::Ablative),
+    ("in", Declension::Ablative),
+    ("on", Declension::Ablative),
+    ("at", Declension::Ablative),
+    ("o", Declension::Vocative),
+];
+
+const ARTICLES: [&str; 3] = ["the", "a", "an"];
+
+/**
+ * The shape of the head word of an english phrase, ex: love/loves, loved, loving
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EnglishShape {
+    Base,
+    Past,
+    Present,
+}
+
+/**
+ * The pronoun a verb phrase starts with, the gender is only known for he, she and it
+ */
+#[derive(Debug, Clone, Copy)]
+struct Subject {
+    person: i8,
+    number: Number,
+    gender: Option<Gender>,
+    length: usize,
+}
+
+/**
+ * One way to read an english phrase, ex: "they will love" -> love, future active indicative 3rd person plural
+ */
+#[derive(Debug, Clone)]
+pub struct EnglishForm {
+    pub head: String,
+    pub pos: PartOfSpeech,
+    pub form: LongForm,
+}
+
+/**
+ * The forms of one latin word that match an english phrase.
+ * ex: amo -> amabunt
+ */
+#[derive(Debug, Serialize)]
+pub struct InflectedTranslation {
+    pub id: i32,
+    pub lemma: String,
+    pub forms: Vec<String>,
+}
+
+/**
+ * An english phrase with the latin forms it translates to, ex: "of the farmers" -> agricolarum
+ */
+#[derive(Debug)]
+pub struct EnglishInflection {
+    pub phrase: String,
+    pub head: String,
+    pub pos: PartOfSpeech,
+    pub form: LongForm,
+    pub translations: Vec<InflectedTranslation>,
+}
+
+impl EnglishInflection {
+    pub fn display(&self) {
+        println!(
+            "{}: {} {}",
+            self.phrase,
+            self.pos.as_str(),
+            self.form.as_clean_str()
+        );
+        for translation in &self.translations {
+            println!(
+                "  {} -> {}: {}",
+                self.head,
+                translation.lemma,
+                translation.forms.join(", ")
+            );
+        }
+        println!();
+    }
+}
+
+impl Serialize for EnglishInflection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut map = serde_json::Map::new();
+        map.insert(
+            "phrase".to_string(),
+            serde_json::Value::String(self.phrase.to_string()),
+        );
+        map.insert(
+            "head".to_string(),
+            serde_json::Value::String(self.head.to_string()),
+        );
+        map.insert(
+            "pos".to_string(),
+            serde_json::Value::String(self.pos.as_str().to_string()),
+        );
+        map.insert(
+            "form".to_string(),
+            serde_json::to_value(&self.form).unwrap(),
+        );
+        map.insert(
+            "translations".to_string(),
+            serde_json::to_value(&self.translations).unwrap(),
+        );
+        serde_json::Value::Object(map).serialize(serializer)
+    }
+}
+
+/**
+ * Translates an english phrase made of a head word and its auxiliaries, prepositions or articles
+ * to the latin forms that say the same thing.
+ * ex: "they will love" -> amabunt, "of the farmers" -> agricolarum, "having been carried" -> latus, -a, -um
+ */
+pub fn inflect_english(
+    english_dictionary: &[EnglishWordInfo],
+    latin_dictionary: &[LatinWordInfo],
+    phrase: &str,
+) -> Vec<EnglishInflection> {
+    let mut output: Vec<EnglishInflection> = Vec::new();
+
+    for english_form in parse_english_form(phrase) {
+        let mut translations: Vec<InflectedTranslation> = Vec::new();
+
+        for word in english_dictionary {
+            if word.orth.to_lowercase() != english_form.head
+                || !pos_matches(word.pos, english_form.pos)
+            {
+                continue;
+            }
+
+            let latin_word = match latin_dictionary.iter().find(|latin| latin.id == word.wid) {
+                Some(latin_word) => latin_word,
+                None => continue,
+            };
+            if translations
+                .iter()
+                .any(|translation| translation.id == latin_word.id)
+            {
+                continue;
+            }
+
+            let forms = inflect_latin(latin_word, &english_form.form);
+            if !forms.is_empty() {
+                translations.push(InflectedTranslation {
+                    id: latin_word.id,
+                    lemma: lemma(latin_word),
+                    forms,
+                });
+            }
+        }
+
+        if !translations.is_empty() {
+            output.push(EnglishInflection {
+                phrase: phrase.to_string(),
+                head: english_form.head,
+                pos: english_form.pos,
+                form: english_form.form,
+                translations,
+            });
+        }
+    }
+
+    output
+}
+
+/**
+ * Reads an english phrase as every form it can be, a noun phrase without a preposition can be
+ * the subject or the object, ex: "the farmers" -> nominative plural, accusative plural
+ */
+pub fn parse_english_form(phrase: &str) -> Vec<EnglishForm> {
+    let words: Vec<String> = phrase
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphabetic() && c != '\'')
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let (head, rest) = match words.split_last() {
+        Some((head, rest)) => (head.as_str(), rest),
+        None => return Vec::new(),
+    };
+
+    let mut forms: Vec<EnglishForm> = Vec::new();
+    forms.extend(parse_verb_phrase(head, rest));
+    forms.extend(parse_noun_phrase(head, rest));
+    forms
+}
+
+fn parse_verb_phrase(head: &str, rest: &[String]) -> Vec<EnglishForm> {
+    let subjects = subjects(rest);
+    if subjects.is_empty() {
+        return parse_verb_forms(head, rest, None);
+    }
+
+    subjects
+        .into_iter()
+        .flat_map(|subject| parse_verb_forms(head, &rest[subject.length..], Some(subject)))
+        .collect()
+}
+
+fn parse_verb_forms(
+    head: &str,
+    auxiliaries: &[String],
+    subject: Option<Subject>,
+) -> Vec<EnglishForm> {
+    let mut forms: Vec<EnglishForm> = Vec::new();
+    let auxiliaries = auxiliaries
+        .iter()
+        .map(|auxiliary| normalize_auxiliary(auxiliary))
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    let phrases: Vec<&(&str, Tense, Voice, Mood)> = match subject {
+        Some(_) => VERB_PHRASES
+            .iter()
+            .filter(|(_, _, _, mood)| *mood != Mood::Infinitive)
+            .collect(),
+        None => VERB_PHRASES
+            .iter()
+            .filter(|(_, _, _, mood)| *mood == Mood::Infinitive)
+            .chain(NON_FINITE_PHRASES.iter())
+            .collect(),
+    };
+
+    for (phrase_auxiliaries, tense, voice, mood) in phrases {
+        if *phrase_auxiliaries != auxiliaries {
+            continue;
+        }
+
+        let shape = head_shape(phrase_auxiliaries, *tense, *voice, *mood);
+        for base in base_forms(head, shape) {
+            let mut form = LongForm::new()
+                .set_tense(*tense)
+                .set_voice(*voice)
+                .set_mood(*mood);
+            if let Some(subject) = subject {
+                form.person = Some(format!("{} person", number_with_ending(subject.person)));
+                form = form.set_number(subject.number);
+                form.gender = subject.gender;
+            }
+
+            forms.push(EnglishForm {
+                head: base,
+                pos: PartOfSpeech::Verb,
+                form,
+            });
+        }
+    }
+
+    forms
+}
+
+fn parse_noun_phrase(head: &str, rest: &[String]) -> Vec<EnglishForm> {
+    let rest: Vec<&str> = rest
+        .iter()
+        .map(|word| word.as_str())
+        .filter(|word| !ARTICLES.contains(word))
+        .collect();
+
+    let cases = match rest[..] {
+        [] => vec![Declension::Nominative, Declension::Accusative],
+        [preposition] => CASE_PREPOSITIONS
+            .iter()
+            .filter(|(case_preposition, _)| *case_preposition == preposition)
+            .map(|(_, case)| *case)
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let mut forms: Vec<EnglishForm> = Vec::new();
+    for case in cases {
+        for (base, number) in noun_bases(head) {
+            for pos in [PartOfSpeech::Noun, PartOfSpeech::Adjective] {
+                forms.push(EnglishForm {
+                    head: base.to_string(),
+                    pos,
+                    form: LongForm::new().set_declension(case).set_number(number),
+                });
+            }
+        }
+    }
+
+    forms
+}
+
+// i, we, you, he, she, it, they, you can be either number
+fn subjects(words: &[String]) -> Vec<Subject> {
+    let first = words.first().map(|word| word.as_str());
+    let second = words.get(1).map(|word| word.as_str());
+    let subject = |person: i8, number: Number, gender: Option<Gender>, length: usize| Subject {
+        person,
+        number,
+        gender,
+        length,
+    };
+
+    match (first, second) {
+        (Some("you"), Some("all")) => vec![subject(2, Number::Plural, None, 2)],
+        (Some("i"), _) => vec![subject(1, Number::Singular, None, 1)],
+        (Some("we"), _) => vec![subject(1, Number::Plural, None, 1)],
+        (Some("you"), _) => vec![
+            subject(2, Number::Singular, None, 1),
+            subject(2, Number::Plural, None, 1),
+        ],
+        (Some("thou"), _) => vec![subject(2, Number::Singular, None, 1)],
+        (Some("ye"), _) => vec![subject(2, Number::Plural, None, 1)],
+        (Some("he"), _) => vec![subject(3, Number::Singular, Some(Gender::Masculine), 1)],
+        (Some("she"), _) => vec![subject(3, Number::Singular, Some(Gender::Feminine), 1)],
+        (Some("it"), _) => vec![subject(3, Number::Singular, Some(Gender::Neuter), 1)],
+        (Some("they"), _) => vec![subject(3, Number::Plural, None, 1)],
+        _ => Vec::new(),
+    }
+}
+
+// is, are, were -> am, was; has -> have; does -> do; might, should, could -> would
+fn normalize_auxiliary(auxiliary: &str) -> &str {
+    match auxiliary {
+        "is" | "are" | "art" => "am",
+        "were" => "was",
+        "has" | "hast" => "have",
+        "does" => "do",
+        "might" | "should" | "could" => "would",
+        "shall" => "will",
+        "going" => "about",
+        auxiliary => auxiliary,
+    }
+}
+
+// the shape the head word has after the auxiliaries, ex: will love, was loving, was loved
+fn head_shape(auxiliaries: &str, tense: Tense, voice: Voice, mood: Mood) -> EnglishShape {
+    let ends_with_have = auxiliaries.ends_with("have") || auxiliaries.ends_with("had");
+
+    if voice == Voice::Passive || ends_with_have || auxiliaries == "having" {
+        EnglishShape::Past
+    } else if (mood == Mood::Participle && tense == Tense::Present)
+        || auxiliaries == "am"
+        || auxiliaries == "was"
+    {
+        EnglishShape::Present
+    } else if auxiliaries.is_empty() && tense == Tense::Perfect {
+        EnglishShape::Past
+    } else {
+        EnglishShape::Base
+    }
+}
+
+/**
+ * The headwords the head of a phrase can come from, ex: loved -> love, taken -> take, loving -> love
+ */
+fn base_forms(word: &str, shape: EnglishShape) -> Vec<String> {
+    match shape {
+        // love, loves -> love
+        EnglishShape::Base => {
+            let mut bases = vec![word.to_string()];
+            bases.extend(lemmas_of_kind(
+                word,
+                &[EnglishFormKind::ThirdPerson, EnglishFormKind::Present],
+            ));
+            bases
+        }
+        // some past participles are the headword, ex: cut
+        EnglishShape::Past => {
+            let mut bases = lemmas_of_kind(
+                word,
+                &[EnglishFormKind::Past, EnglishFormKind::PastParticiple],
+            );
+            bases.push(word.to_string());
+            bases
+        }
+        EnglishShape::Present => lemmas_of_kind(word, &[EnglishFormKind::PresentParticiple]),
+    }
+}
+
+// farmers -> farmer (plural), children -> child (plural), farmer -> farmer (singular)
+fn noun_bases(word: &str) -> Vec<(String, Number)> {
+    let mut bases = vec![(word.to_string(), Number::Singular)];
+    for lemma in lemmas_of_kind(word, &[EnglishFormKind::Plural]) {
+        bases.push((lemma, Number::Plural));
+    }
+    bases
+}
+
+fn pos_matches(english_pos: PartOfSpeech, pos: PartOfSpeech) -> bool {
+    english_pos == pos || (pos == PartOfSpeech::Verb && english_pos == PartOfSpeech::Participle)
+}
+
+/**
+ * Builds the forms of a latin word that match a form, ex: amo, future active indicative 3rd person plural -> amabunt
+ * Participles are given with the endings of each gender, ex: latus, -a, -um
+ */
+fn inflect_latin(word: &LatinWordInfo, form: &LongForm) -> Vec<String> {
+    match word.pos {
+        PartOfSpeech::Verb => {
+            let person = form
+                .person
+                .as_ref()
+                .and_then(|person| person.chars().next())
+                .and_then(|person| person.to_digit(10))
+                .map_or(3, |person| person as i8);
+            let number = form.number.unwrap_or(Number::Singular);
+
+            let forms = match synopsis(word, person, number, true) {
+                Ok(forms) => forms,
+                Err(_) => return Vec::new(),
+            };
+
+            forms
+                .into_iter()
+                .filter(|synopsis_form| {
+                    Some(synopsis_form.tense) == form.tense
+                        && Some(synopsis_form.voice) == form.voice
+                        && Some(synopsis_form.mood) == form.mood
+                })
+                .flat_map(|synopsis_form| synopsis_form.forms)
+                .map(|latin_form| match latin_form.strip_suffix("us") {
+                    Some(stem) if form.mood == Some(Mood::Participle) && !stem.is_empty() => {
+                        format!("{}us, -a, -um", stem)
+                    }
+                    _ => agree_with_subject(&latin_form, form.gender),
+                })
+                .collect()
+        }
+        PartOfSpeech::Noun | PartOfSpeech::Adjective => {
+            let (case, number) = match (form.declension, form.number) {
+                (Some(case), Some(number)) => (case, number),
+                _ => return Vec::new(),
+            };
+
+            // a noun has its own gender, an adjective agrees with any
+            let genders = if word.pos == PartOfSpeech::Adjective {
+                GENDERS[..3].to_vec()
+            } else {
+                match word_gender(word) {
+                    gender @ (Gender::Masculine | Gender::Feminine | Gender::Neuter) => {
+                        vec![gender]
+                    }
+                    // common nouns decline the same in both genders, ex: civis
+                    _ => vec![Gender::Masculine],
+                }
+            };
+
+            let table = decline(word, &genders);
+            let mut forms: Vec<String> = Vec::new();
+            for gender in genders {
+                for latin_form in table.get(case, number, gender) {
+                    if !forms.contains(latin_form) {
+                        forms.push(latin_form.to_string());
+                    }
+                }
+            }
+            forms
+        }
+        _ => Vec::new(),
+    }
+}
+
+// the participle of a compound form agrees with the subject, ex: she was seized -> capta est
+fn agree_with_subject(latin_form: &str, gender: Option<Gender>) -> String {
+    let (participle, auxiliary) = match latin_form.split_once(' ') {
+        Some(parts) => parts,
+        None => return latin_form.to_string(),
+    };
+
+    let participle = match (participle.strip_suffix("us"), gender) {
+        (Some(stem), Some(Gender::Feminine)) => format!("{}a", stem),
+        (Some(stem), Some(Gender::Neuter)) => format!("{}um", stem),
+        _ => participle.to_string(),
+    };
+
+    format!("{} {}", participle, auxiliary)
+}
+
+// amo, agricola, bonus
+fn lemma(word: &LatinWordInfo) -> String {
+    let lemma = match word.pos {
+        PartOfSpeech::Adjective => positive_lemma(word),
+        _ => word
+            .principle_parts()
+            .ok()
+            .and_then(|parts| parts.first().cloned()),
+    };
+
+    lemma.unwrap_or_else(|| word.orth.to_string())
+}
@@ -0,0 +1,206 @@
+use crate::dictionary_structures::dictionary_keys::{EnglishFormKind, PartOfSpeech};
+use crate::dictionary_structures::dictionary_values::{EnglishWordInfo, LatinWordInfo};
+use crate::translators::english_to_latin::lemmatizer::{lemmas_of_kind, EnglishLemma};
+use crate::translators::english_to_latin::EnglishTranslationInfo;
+use serde::{Deserialize, Serialize};
+
+// longer phrases are not found in the senses, ex: take care of oneself
+pub const MAX_PHRASE_WORDS: usize = 4;
+
+// phrases of only these words are in too many glosses to mean anything, ex: of the
+const FUNCTION_WORDS: [&str; 16] = [
+    "a", "an", "the", "of", "to", "in", "on", "at", "by", "for", "with", "from", "and", "or", "is",
+    "be",
+];
+
+/**
+ * How exactly a phrase matched one of the glosses of a sense, best first
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum PhraseMatch {
+    // take care -> take care (of)
+    Exact,
+    // take care -> take care of
+    Start,
+    // make war -> to make war on
+    Within,
+}
+
+impl PhraseMatch {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhraseMatch::Exact => "exact",
+            PhraseMatch::Start => "start of a gloss",
+            PhraseMatch::Within => "within a gloss",
+        }
+    }
+}
+
+/**
+ * Finds the longest phrase at the start of the words that is in the senses of the dictionary, ex: take care of him -> take care of
+ * Returns the number of words used and the latin words, ranked by how exactly the phrase matched
+ */
+pub fn find_longest_phrase(
+    latin_dictionary: &[LatinWordInfo],
+    english_words: &[String],
+    max: usize,
+) -> Option<(usize, Vec<EnglishTranslationInfo>)> {
+    let longest = MAX_PHRASE_WORDS.min(english_words.len());
+    (2..=longest).rev().find_map(|length| {
+        let translations =
+            translate_english_phrase(latin_dictionary, &english_words[..length], max);
+        if translations.is_empty() {
+            None
+        } else {
+            Some((length, translations))
+        }
+    })
+}
+
+/**
+ * Finds the latin words with a gloss that contains the phrase, ex: set out -> proficiscor
+ * An inflected first word is also tried as its headword, ex: took care -> take care
+ */
+pub fn translate_english_phrase(
+    latin_dictionary: &[LatinWordInfo],
+    english_words: &[String],
+    max: usize,
+) -> Vec<EnglishTranslationInfo> {
+    let Some((first, rest)) = english_words.split_first() else {
+        return Vec::new();
+    };
+    if english_words
+        .iter()
+        .all(|word| FUNCTION_WORDS.contains(&word.as_str()))
+    {
+        return Vec::new();
+    }
+
+    let mut phrases: Vec<(Vec<String>, Option<EnglishLemma>)> =
+        vec![(english_words.to_vec(), None)];
+    let verb_kinds = [
+        EnglishFormKind::ThirdPerson,
+        EnglishFormKind::Past,
+        EnglishFormKind::PastParticiple,
+        EnglishFormKind::PresentParticiple,
+    ];
+    for kind in verb_kinds {
+        for lemma in lemmas_of_kind(first, &[kind]) {
+            let mut words = vec![lemma];
+            words.extend(rest.iter().cloned());
+            let headword = words.join(" ");
+            if !phrases
+                .iter()
+                .any(|(phrase, _)| phrase.join(" ") == headword)
+            {
+                phrases.push((
+                    words,
+                    Some(EnglishLemma {
+                        lemma: headword,
+                        kind,
+                    }),
+                ));
+            }
+        }
+    }
+
+    let mut matches: Vec<(PhraseMatch, EnglishTranslationInfo)> = Vec::new();
+    for word in latin_dictionary {
+        let best = phrases
+            .iter()
+            .filter(|(_, lemma)| {
+                lemma
+                    .as_ref()
+                    .is_none_or(|_| word.pos == PartOfSpeech::Verb)
+            })
+            .filter_map(|(phrase, lemma)| {
+                match_senses(&word.senses, phrase).map(|phrase_match| (phrase_match, lemma))
+            })
+            .min_by_key(|(phrase_match, _)| *phrase_match);
+
+        if let Some((phrase_match, lemma)) = best {
+            let mut english_word = EnglishWordInfo::new();
+            english_word.set_orth(&english_words.join(" "));
+            english_word.set_wid(word.id);
+            english_word.set_pos(word.pos);
+            english_word.set_frequency_type(word.info.freq);
+            english_word.set_compound(english_words.len() as i16);
+
+            let mut translation = EnglishTranslationInfo::new(english_word, LatinWordInfo::new());
+            translation.translation.set_word(word);
+            translation.set_lemma(lemma.clone());
+            translation.set_phrase_match(Some(phrase_match));
+            matches.push((phrase_match, translation));
+        }
+    }
+
+    // the most exact matches first, then the most frequent words
+    matches.sort_by(|(a_match, a), (b_match, b)| {
+        a_match.cmp(b_match).then(
+            a.translation
+                .info
+                .freq
+                .as_number()
+                .cmp(&b.translation.info.freq.as_number()),
+        )
+    });
+    matches.truncate(max);
+
+    matches
+        .into_iter()
+        .map(|(_, translation)| translation)
+        .collect()
+}
+
+// the best match of the phrase in any gloss of the senses, glosses are split by , and ;
+fn match_senses(senses: &[String], phrase: &[String]) -> Option<PhraseMatch> {
+    senses
+        .iter()
+        .flat_map(|sense| sense.split([',', ';']))
+        .filter_map(|gloss| match_gloss(gloss, phrase))
+        .min()
+}
+
+fn match_gloss(gloss: &str, phrase: &[String]) -> Option<PhraseMatch> {
+    let words = gloss_words(gloss, true);
+    if words.len() < phrase.len() {
+        return None;
+    }
+
+    // take care (of) -> take care
+    if words == phrase || gloss_words(gloss, false) == phrase {
+        Some(PhraseMatch::Exact)
+    } else if words.starts_with(phrase) {
+        Some(PhraseMatch::Start)
+    } else if words.windows(phrase.len()).any(|window| window == phrase) {
+        Some(PhraseMatch::Within)
+    } else {
+        None
+    }
+}
+
+// the lowercase words of a gloss, with or without the words in parentheses, ex: take care (of)
+fn gloss_words(gloss: &str, keep_parentheses: bool) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut depth = 0;
+    let mut word = String::new();
+    for c in gloss.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = (depth - 1).max(0),
+            _ => {}
+        }
+        if c.is_alphanumeric() || c == '\'' {
+            if keep_parentheses || depth == 0 {
+                word.extend(c.to_lowercase());
+            }
+        } else if !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
@@ -1,9 +1,14 @@
+use crate::paradigms::all_forms;
 use crate::translators::english_to_latin::EnglishTranslationInfo;
+use crate::utils::sorting::{sort_entries, SortBy};
 
-pub fn weigh_words(word_list: Vec<EnglishTranslationInfo>) -> Vec<EnglishTranslationInfo> {
-    let mut weighted_word_list = word_list;
-    weighted_word_list.sort_by(|a, b| b.word.true_frequency.cmp(&a.word.true_frequency));
-    weighted_word_list
+pub fn weigh_words(
+    word_list: Vec<EnglishTranslationInfo>,
+    sort_by: &[SortBy],
+) -> Vec<EnglishTranslationInfo> {
+    sort_entries(word_list, sort_by, |info| {
+        all_forms(&info.translation).len()
+    })
 }
 
 pub fn remove_duplicates(word_list: Vec<EnglishTranslationInfo>) -> Vec<EnglishTranslationInfo> {
@@ -0,0 +1,139 @@
+use crate::dictionary_structures::dictionary_values::Abbreviation;
+use crate::utils::data::get_latin_abbreviations;
+use std::env;
+
+// the longest abbreviation written as separate words, ex: trib. pl.
+const MAX_ABBREVIATION_WORDS: usize = 3;
+
+// a json file with more abbreviations, in the same format as latin_abbreviations.json
+pub const USER_ABBREVIATIONS_VARIABLE: &str = "VOCAB_VAULT_ABBREVIATIONS";
+
+/**
+ * Adds the abbreviations from the file in VOCAB_VAULT_ABBREVIATIONS to the bundled table.
+ * A user abbreviation replaces every bundled abbreviation with the same orth.
+ * If the file cannot be read or parsed, a warning is printed and only the bundled table is used.
+ * Inscriptions also read the epigraphic abbreviations, which win over the others with the same orth, ex: p c
+ */
+pub fn load_abbreviations(epigraphic: bool) -> Vec<Abbreviation> {
+    let abbreviations = add_user_abbreviations(get_latin_abbreviations());
+    if !epigraphic {
+        return abbreviations
+            .into_iter()
+            .filter(|abbreviation| !abbreviation.epigraphic)
+            .collect();
+    }
+
+    let epigraphic_orths: Vec<String> = abbreviations
+        .iter()
+        .filter(|abbreviation| abbreviation.epigraphic)
+        .map(|abbreviation| abbreviation.orth.to_string())
+        .collect();
+    abbreviations
+        .into_iter()
+        .filter(|abbreviation| {
+            abbreviation.epigraphic || !epigraphic_orths.contains(&abbreviation.orth)
+        })
+        .collect()
+}
+
+fn add_user_abbreviations(bundled: Vec<Abbreviation>) -> Vec<Abbreviation> {
+    let path = match env::var(USER_ABBREVIATIONS_VARIABLE) {
+        Ok(path) => path,
+        Err(_) => return bundled,
+    };
+
+    // a bad file only costs the user abbreviations, the warning goes to stderr to keep the json output valid
+    let user_abbreviations_json = match std::fs::read(&path) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!(
+                "Error reading abbreviations from {}, using the bundled ones: {}",
+                path, e
+            );
+            return bundled;
+        }
+    };
+
+    let user_abbreviations: Vec<Abbreviation> =
+        match serde_json::from_slice(&user_abbreviations_json) {
+            Ok(abbreviations) => abbreviations,
+            Err(e) => {
+                eprintln!(
+                    "Error parsing abbreviations from {}, using the bundled ones: {}",
+                    path, e
+                );
+                return bundled;
+            }
+        };
+
+    let mut abbreviations: Vec<Abbreviation> = bundled
+        .into_iter()
+        .filter(|abbreviation| {
+            !user_abbreviations
+                .iter()
+                .any(|user_abbreviation| user_abbreviation.orth == abbreviation.orth)
+        })
+        .collect();
+    abbreviations.extend(user_abbreviations);
+
+    abbreviations
+}
+
+/**
+ * Finds the abbreviations at the start of the words, before they are sanitized.
+ * Abbreviations are marked with periods (M., S.P.Q.R., trib. pl.) or written as capitals (SPQR).
+ * Returns the matching abbreviations and how many words they use, the longest match wins.
+ */
+pub fn find_abbreviation(
+    words: &[&str],
+    abbreviations: &[Abbreviation],
+) -> Option<(Vec<Abbreviation>, usize)> {
+    for length in (1..=words.len().min(MAX_ABBREVIATION_WORDS)).rev() {
+        let words = &words[..length];
+
+        let orth = if words.iter().all(|word| word.contains('.')) {
+            dotted_orth(words)
+        } else if length == 1 && is_capitalized_acronym(words[0]) {
+            words[0]
+                .to_lowercase()
+                .chars()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        } else {
+            continue;
+        };
+
+        let matches: Vec<Abbreviation> = abbreviations
+            .iter()
+            .filter(|abbreviation| abbreviation.orth == orth)
+            .cloned()
+            .collect();
+
+        if !matches.is_empty() {
+            return Some((matches, length));
+        }
+    }
+
+    None
+}
+
+// S.P.Q.R. -> "s p q r", trib. pl. -> "trib pl"
+fn dotted_orth(words: &[&str]) -> String {
+    words
+        .iter()
+        .flat_map(|word| word.split('.'))
+        .map(|part| {
+            part.chars()
+                .filter(|c| c.is_alphabetic())
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn is_capitalized_acronym(word: &str) -> bool {
+    word.chars().count() >= 2 && word.chars().all(|c| c.is_ascii_uppercase())
+}
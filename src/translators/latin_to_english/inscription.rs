@@ -0,0 +1,396 @@
+use crate::dictionary_structures::dictionary_values::Abbreviation;
+use crate::translators::latin_to_english::abbreviations::load_abbreviations;
+use crate::translators::latin_to_english::trace::Trace;
+use crate::translators::latin_to_english::{translate_latin_to_english, LatinTranslationInfo};
+use crate::translators::{DisplayType, Language, Translation, TranslationType};
+use crate::utils::data::{
+    get_latin_inflections, get_latin_stems, get_latin_tackons, get_unique_latin_words,
+};
+use crate::utils::sorting::SortBy;
+use serde::Serialize;
+use std::collections::HashMap;
+
+// word dividers used in inscriptions and manuscripts
+const SEPARATORS: [char; 8] = [' ', '·', '•', '‧', '⋅', '.', ':', ','];
+
+const MAX_WORD_LENGTH: usize = 20;
+const MAX_SEGMENTATIONS: usize = 3;
+const MAX_READINGS: usize = 4;
+
+// a letter that is not part of any word, kept so a segmentation is always found
+const UNKNOWN_LETTER_COST: u32 = 8;
+// very short words make it too easy to cut a word in pieces
+const SHORT_WORD_COST: u32 = 2;
+const ABBREVIATION_COST: u32 = 2;
+const UNANALYZED_WORD_COST: u32 = 5;
+
+#[derive(Debug, Serialize)]
+pub struct InscriptionWord {
+    pub word: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abbreviation: Option<Abbreviation>,
+    pub translation: Translation,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Segmentation {
+    pub score: u32,
+    pub words: Vec<InscriptionWord>,
+}
+
+/**
+ * A run of text between word dividers, and the best ways it can be cut into words.
+ * Lower scores are better.
+ */
+#[derive(Debug, Serialize)]
+pub struct InscriptionReading {
+    pub text: String,
+    pub segmentations: Vec<Segmentation>,
+}
+
+impl InscriptionReading {
+    pub fn display(&self, detailed: bool) {
+        println!("{}:", self.text);
+
+        for (index, segmentation) in self.segmentations.iter().enumerate() {
+            let words: Vec<&str> = segmentation
+                .words
+                .iter()
+                .map(|word| word.translation.word.as_str())
+                .collect();
+            println!(
+                "  reading {} (score {}): {}",
+                index + 1,
+                segmentation.score,
+                words.join(" ")
+            );
+        }
+        println!();
+
+        // only the best reading is analyzed in full
+        if let Some(segmentation) = self.segmentations.first() {
+            for word in &segmentation.words {
+                match &word.abbreviation {
+                    Some(abbreviation) => {
+                        println!("{}: {}", word.word, abbreviation.expansion);
+                        println!("abbreviation");
+                        for sense in &abbreviation.senses {
+                            print!("{} ", sense);
+                        }
+                        println!("\n");
+                    }
+                    None => word.translation.display(DisplayType::Pretty(detailed)),
+                }
+            }
+        }
+    }
+}
+
+/**
+ * Normalizes the spellings used in inscriptions, u and v are both written u, and j is written i.
+ */
+pub fn normalize_inscription(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'v' => 'u',
+            'j' => 'i',
+            c => c,
+        })
+        .collect()
+}
+
+/**
+ * Reads text written without spaces or with word dividers, ex: SENATVSPOPVLVSQVEROMANVS, D·M·S
+ * Abbreviations are expanded with the epigraphic ones first, and every run of letters is
+ * cut into the words that give the best analysis.
+ */
+pub fn read_inscription(
+    text: &str,
+    tricks: bool,
+    max: usize,
+    sort_by: &[SortBy],
+) -> Vec<InscriptionReading> {
+    let normalized_text = normalize_inscription(text);
+    let tokens: Vec<String> = normalized_text
+        .split(&SEPARATORS[..])
+        .map(|token| token.chars().filter(|c| c.is_alphabetic()).collect())
+        .filter(|token: &String| !token.is_empty())
+        .collect();
+
+    let abbreviations = load_abbreviations(true);
+    let index = WordIndex::new(&abbreviations);
+    let mut analyses: HashMap<String, Vec<LatinTranslationInfo>> = HashMap::new();
+    let mut readings: Vec<InscriptionReading> = Vec::new();
+
+    let mut position = 0;
+    while position < tokens.len() {
+        // the longest abbreviation written with dividers, ex: d·m·s before d·m
+        let abbreviation = abbreviations
+            .iter()
+            .filter(|abbreviation| {
+                let parts: Vec<String> = abbreviation
+                    .orth
+                    .split_whitespace()
+                    .map(normalize_inscription)
+                    .collect();
+                tokens[position..].starts_with(&parts)
+            })
+            .max_by_key(|abbreviation| abbreviation.orth.split_whitespace().count());
+
+        if let Some(abbreviation) = abbreviation {
+            let length = abbreviation.orth.split_whitespace().count();
+            let text = tokens[position..position + length].join("·");
+            let word = abbreviation_word(&text, abbreviation);
+
+            readings.push(InscriptionReading {
+                text,
+                segmentations: vec![Segmentation {
+                    score: ABBREVIATION_COST,
+                    words: vec![word],
+                }],
+            });
+            position += length;
+            continue;
+        }
+
+        let token = &tokens[position];
+        let mut segmentations: Vec<Segmentation> = Vec::new();
+        for (score, words) in index.segment(token) {
+            let mut segmentation = Segmentation {
+                score,
+                words: Vec::new(),
+            };
+
+            for word in words {
+                if let Some(abbreviation) = index.abbreviations.get(&word) {
+                    segmentation
+                        .words
+                        .push(abbreviation_word(&word, abbreviation));
+                    continue;
+                }
+
+                let mut definitions: Vec<LatinTranslationInfo> = Vec::new();
+                let word_readings = index.readings(&word);
+                for reading in &word_readings {
+                    let reading_definitions =
+                        analyses.entry(reading.to_string()).or_insert_with(|| {
+                            translate_latin_to_english(reading, tricks, &mut Trace::new(false))
+                        });
+                    definitions.extend(reading_definitions.iter().cloned());
+                }
+
+                if definitions.is_empty() {
+                    segmentation.score += UNANALYZED_WORD_COST;
+                }
+                definitions.truncate(max);
+
+                let reading = word_readings.first().cloned().unwrap_or(word.clone());
+                let mut translation =
+                    Translation::new(reading, TranslationType::Latin(definitions));
+                translation.post_process(Language::Latin, sort_by);
+
+                segmentation.words.push(InscriptionWord {
+                    word,
+                    abbreviation: None,
+                    translation,
+                });
+            }
+
+            segmentations.push(segmentation);
+        }
+
+        segmentations.sort_by_key(|segmentation| segmentation.score);
+        readings.push(InscriptionReading {
+            text: token.to_string(),
+            segmentations,
+        });
+        position += 1;
+    }
+
+    readings
+}
+
+fn abbreviation_word(text: &str, abbreviation: &Abbreviation) -> InscriptionWord {
+    InscriptionWord {
+        word: text.to_string(),
+        abbreviation: Some(abbreviation.clone()),
+        translation: Translation::new(
+            abbreviation.expansion.to_string(),
+            TranslationType::Latin(Vec::new()),
+        ),
+    }
+}
+
+/**
+ * The stems, endings and whole words of the dictionary in the normalized spelling,
+ * so a run of letters can be checked for words without analyzing every piece of it.
+ */
+struct WordIndex {
+    stems: HashMap<String, Vec<String>>,
+    endings: HashMap<String, Vec<String>>,
+    words: HashMap<String, Vec<String>>,
+    tackons: Vec<String>,
+    abbreviations: HashMap<String, Abbreviation>,
+}
+
+impl WordIndex {
+    fn new(abbreviations: &[Abbreviation]) -> WordIndex {
+        let mut index = WordIndex {
+            stems: HashMap::new(),
+            endings: HashMap::new(),
+            words: HashMap::new(),
+            tackons: get_latin_tackons()
+                .into_iter()
+                .map(|tackon| tackon.orth)
+                .collect(),
+            abbreviations: HashMap::new(),
+        };
+
+        for stem in get_latin_stems() {
+            if !stem.orth.is_empty() {
+                add_spelling(&mut index.stems, &stem.orth);
+            }
+        }
+
+        for inflection in get_latin_inflections() {
+            if !inflection.ending.contains(' ') {
+                add_spelling(&mut index.endings, &inflection.ending);
+            }
+        }
+
+        for word in get_unique_latin_words() {
+            add_spelling(&mut index.words, &word.orth.to_lowercase());
+        }
+
+        // abbreviations written without dividers can be found in running text, ex: spqr
+        for abbreviation in abbreviations {
+            let joined = normalize_inscription(&abbreviation.orth.replace(' ', ""));
+            if joined.len() >= 3 {
+                index.abbreviations.insert(joined, abbreviation.clone());
+            }
+        }
+
+        index
+    }
+
+    /**
+     * Returns the dictionary spellings the word can be read as, or nothing if it is not a word.
+     * ex: uirumque -> virumque
+     */
+    fn readings(&self, word: &str) -> Vec<String> {
+        let mut readings: Vec<String> = Vec::new();
+
+        let mut hosts: Vec<(&str, &str)> = vec![(word, "")];
+        for tackon in &self.tackons {
+            if let Some(host) = word.strip_suffix(tackon.as_str()) {
+                if !host.is_empty() {
+                    hosts.push((host, tackon));
+                }
+            }
+        }
+
+        for (host, tackon) in hosts {
+            for spelling in self.words.get(host).into_iter().flatten() {
+                let reading = format!("{}{}", spelling, tackon);
+                if !readings.contains(&reading) {
+                    readings.push(reading);
+                }
+            }
+
+            for (split, _) in host.char_indices().skip(1) {
+                let (stem, ending) = host.split_at(split);
+                self.add_stem_readings(&mut readings, stem, ending, tackon);
+            }
+            self.add_stem_readings(&mut readings, host, "", tackon);
+        }
+
+        readings.truncate(MAX_READINGS);
+        readings
+    }
+
+    fn add_stem_readings(
+        &self,
+        readings: &mut Vec<String>,
+        stem: &str,
+        ending: &str,
+        tackon: &str,
+    ) {
+        let (stems, endings) = match (self.stems.get(stem), self.endings.get(ending)) {
+            (Some(stems), Some(endings)) => (stems, endings),
+            _ => return,
+        };
+
+        for stem in stems {
+            for ending in endings {
+                let reading = format!("{}{}{}", stem, ending, tackon);
+                if !readings.contains(&reading) {
+                    readings.push(reading);
+                }
+            }
+        }
+    }
+
+    fn word_cost(&self, word: &str) -> Option<u32> {
+        if self.abbreviations.contains_key(word) {
+            return Some(ABBREVIATION_COST);
+        }
+
+        if self.readings(word).is_empty() {
+            return None;
+        }
+
+        match word.chars().count() {
+            0..=2 => Some(1 + SHORT_WORD_COST),
+            _ => Some(1),
+        }
+    }
+
+    /**
+     * Cuts the text into words by dynamic programming, keeping the best few segmentations at every position.
+     */
+    fn segment(&self, text: &str) -> Vec<(u32, Vec<String>)> {
+        let letters: Vec<char> = text.chars().collect();
+        let mut best: Vec<Vec<(u32, Vec<String>)>> = vec![Vec::new(); letters.len() + 1];
+        best[0].push((0, Vec::new()));
+
+        for start in 0..letters.len() {
+            if best[start].is_empty() {
+                continue;
+            }
+
+            let mut words: Vec<(usize, String, u32)> = Vec::new();
+            for end in start + 1..=letters.len().min(start + MAX_WORD_LENGTH) {
+                let word: String = letters[start..end].iter().collect();
+                if let Some(cost) = self.word_cost(&word) {
+                    words.push((end, word, cost));
+                }
+            }
+
+            if words.is_empty() {
+                words.push((start + 1, letters[start].to_string(), UNKNOWN_LETTER_COST));
+            }
+
+            let paths = best[start].clone();
+            for (end, word, cost) in words {
+                for (score, path) in &paths {
+                    let mut path = path.clone();
+                    path.push(word.clone());
+                    best[end].push((score + cost, path));
+                }
+                best[end].sort_by_key(|(score, path)| (*score, path.len()));
+                best[end].truncate(MAX_SEGMENTATIONS);
+            }
+        }
+
+        best.pop().unwrap_or_default()
+    }
+}
+
+fn add_spelling(spellings: &mut HashMap<String, Vec<String>>, spelling: &str) {
+    let normalized = normalize_inscription(spelling);
+    let entry = spellings.entry(normalized).or_default();
+    if !entry.iter().any(|existing| existing == spelling) {
+        entry.push(spelling.to_string());
+    }
+}
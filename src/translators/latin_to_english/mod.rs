@@ -1,8 +1,13 @@
+pub mod abbreviations;
+pub mod inscription;
 pub mod parser;
+pub mod principle_parts;
+pub mod trace;
 pub mod translator;
 pub mod tricks;
 pub mod utils;
 
+use self::tricks::contractions::try_contractions;
 use self::tricks::{try_syncopes, try_tricks};
 use crate::dictionary_structures::dictionary_keys::{
     Age, Area, Frequency, Geography, PartOfSpeech, Source,
@@ -10,11 +15,13 @@ use crate::dictionary_structures::dictionary_keys::{
 use crate::dictionary_structures::dictionary_values::{
     Form, Inflection, LatinWordInfo, LongForm, Stem,
 };
+use crate::paradigms::comparison::ComparedForm;
 use crate::utils::{evaluate_roman_numeral, is_roman_number};
 use serde::{Deserialize, Serialize};
 
 use self::parser::parse;
-use self::utils::split_enclitic;
+use self::trace::Trace;
+use self::utils::{rank_analyses, split_enclitic, split_tickon};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LatinTranslationInfo {
@@ -22,6 +29,11 @@ pub struct LatinTranslationInfo {
     pub word: LatinWordInfo,
     pub stem: Stem,
     pub inflections: Option<Vec<Inflection>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compared: Option<ComparedForm>,
+    // the number of the entry among the definitions with the same headword, ex: liber 2 (book)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homograph: Option<usize>,
 }
 
 impl LatinTranslationInfo {
@@ -31,6 +43,8 @@ impl LatinTranslationInfo {
             word: LatinWordInfo::new(),
             stem: Stem::new(),
             inflections: None,
+            compared: None,
+            homograph: None,
         }
     }
 
@@ -58,16 +72,32 @@ impl LatinTranslationInfo {
         self.inflections = Some(inflections);
     }
 
+    pub fn set_compared(&mut self, compared: ComparedForm) {
+        self.compared = Some(compared);
+    }
+
+    pub fn set_homograph(&mut self, homograph: usize) {
+        self.homograph = Some(homograph);
+    }
+
     pub fn set_tricks(&mut self, tricks: &Vec<String>) {
         self.tricks = Some(tricks.to_vec());
     }
 }
 
-pub fn translate_latin_to_english(latin_word: &str, tricks: bool) -> Vec<LatinTranslationInfo> {
+pub fn translate_latin_to_english(
+    latin_word: &str,
+    tricks: bool,
+    trace: &mut Trace,
+) -> Vec<LatinTranslationInfo> {
+    trace.start_stage("roman numeral", latin_word);
     if is_roman_number(&latin_word) {
         match evaluate_roman_numeral(&latin_word) {
             Ok(number) => {
                 if number > 0 {
+                    if trace.is_enabled() {
+                        trace.accept(latin_word, &format!("evaluates to {}", number));
+                    }
                     let mut translation = LatinTranslationInfo::new();
                     translation.word.set_orth(&latin_word);
                     translation
@@ -90,59 +120,152 @@ pub fn translate_latin_to_english(latin_word: &str, tricks: bool) -> Vec<LatinTr
                 return Vec::new();
             }
         }
+        trace.reject(
+            latin_word,
+            "roman digits do not evaluate to a positive number",
+        );
+    } else {
+        trace.reject(latin_word, "contains letters that are not roman digits");
     }
 
-    let mut output = parse(&latin_word, false);
+    let mut output = parse(&latin_word, false, trace).unwrap_or_default();
 
     if tricks {
-        let trick_results = try_tricks(&latin_word);
+        output.extend(parse_with_tricks(latin_word, trace));
+        output.extend(parse_contractions(latin_word, trace));
+    }
 
-        let mut modified_word = if trick_results.is_found() {
-            trick_results.get_word()
-        } else {
-            latin_word.to_string()
-        };
-        let mut explanations = trick_results.get_explanations();
+    // enclitics are split alongside the other lookups, and the split word can still need tricks or reducing
+    // ex: virumque -> virum + que, salve is kept whole by latin_enclitic_exceptions.json
+    // and rank_analyses drops the splits of an entry that is also found unsplit
+    for (split_word, modifiers) in split_enclitic(&latin_word, trace) {
+        let mut split_output = parse(&split_word, false, trace).unwrap_or_default();
 
-        let syncope_results = try_syncopes(&modified_word);
+        if tricks {
+            split_output.extend(parse_with_tricks(&split_word, trace));
+            split_output.extend(parse_contractions(&split_word, trace));
+        }
 
-        if syncope_results.get_word() != modified_word && syncope_results.is_found() {
-            modified_word = syncope_results.get_word();
-            explanations.extend(syncope_results.get_explanations());
+        for word in split_output.iter_mut() {
+            let mut word_modifiers = word.word.modifiers.take().unwrap_or_default();
+            word_modifiers.extend(modifiers.clone());
+            word.word.set_modifiers(word_modifiers);
         }
 
-        if modified_word != latin_word && modified_word != String::new() {
-            let mut new_output = parse(&modified_word, false);
+        output.extend(split_output);
+    }
 
-            if new_output.is_some() {
-                for word in new_output.as_mut().unwrap() {
-                    word.set_tricks(&explanations);
-                }
+    // tickons are prefixed to the qui/quis pronouns, ex: siquis -> si + quis
+    for (split_word, tickon) in split_tickon(latin_word, trace) {
+        let mut split_output = parse(&split_word, false, trace).unwrap_or_default();
 
-                if output.is_some() {
-                    output.as_mut().unwrap().extend(new_output.unwrap());
-                } else {
-                    output = new_output;
+        for (packon_word, modifiers) in split_enclitic(&split_word, trace) {
+            let mut packon_output = parse(&packon_word, false, trace).unwrap_or_default();
+            for word in packon_output.iter_mut() {
+                let mut word_modifiers = word.word.modifiers.take().unwrap_or_default();
+                word_modifiers.extend(modifiers.clone());
+                word.word.set_modifiers(word_modifiers);
+            }
+            split_output.extend(packon_output);
+        }
+
+        trace.start_stage("split tickon", latin_word);
+        for mut word in split_output {
+            if word.word.pos != PartOfSpeech::Pronoun && word.word.pos != PartOfSpeech::Packon {
+                if trace.is_enabled() {
+                    trace.reject(
+                        &format!("{}-{} ({})", tickon.orth, split_word, word.word.id),
+                        &format!("tickons do not attach to a {}", word.word.pos.as_str()),
+                    );
                 }
+                continue;
+            }
+
+            if trace.is_enabled() {
+                trace.accept(
+                    &format!("{}-{} ({})", tickon.orth, split_word, word.word.id),
+                    &format!(
+                        "{}- attached to the pronoun {}",
+                        tickon.orth, word.word.orth
+                    ),
+                );
             }
+            let mut modifiers = vec![tickon.clone()];
+            modifiers.extend(word.word.modifiers.take().unwrap_or_default());
+            word.word.set_modifiers(modifiers);
+            output.push(word);
         }
     }
 
-    // most words should be found by now
+    rank_analyses(output)
+}
+
+fn parse_with_tricks(latin_word: &str, trace: &mut Trace) -> Vec<LatinTranslationInfo> {
+    trace.start_stage("tricks", latin_word);
+    let trick_results = try_tricks(&latin_word);
 
-    // If nothing is found, try removing enclitics and try again
-    // ex: clamaverunt -> clamare
-    // doing this here instead of earlier should fix words like salve having the "ve" removed and returning wrong def
-    if output.is_none() {
-        let (word_without_ecliptics, modifiers) = split_enclitic(&latin_word);
-        output = parse(&word_without_ecliptics, false);
+    let mut modified_word = if trick_results.is_found() {
+        trick_results.get_word()
+    } else {
+        latin_word.to_string()
+    };
+    let mut explanations = trick_results.get_explanations();
 
-        if output.is_some() {
-            for word in output.as_mut().unwrap() {
-                word.word.set_modifiers(modifiers.clone());
+    let syncope_results = try_syncopes(&modified_word);
+
+    if syncope_results.get_word() != modified_word && syncope_results.is_found() {
+        modified_word = syncope_results.get_word();
+        explanations.extend(syncope_results.get_explanations());
+    }
+
+    for explanation in &explanations {
+        trace.consider(&modified_word, explanation);
+    }
+
+    if modified_word == latin_word || modified_word == String::new() {
+        trace.reject(latin_word, "no trick applies to the word");
+        return Vec::new();
+    }
+
+    let new_output = parse(&modified_word, false, trace);
+
+    trace.start_stage("tricks", latin_word);
+    match new_output {
+        Some(mut new_output) => {
+            trace.accept(&modified_word, "tricked word was found");
+            for word in new_output.iter_mut() {
+                word.set_tricks(&explanations);
+            }
+            new_output
+        }
+        None => {
+            trace.reject(&modified_word, "tricked word was not found");
+            Vec::new()
+        }
+    }
+}
+
+fn parse_contractions(latin_word: &str, trace: &mut Trace) -> Vec<LatinTranslationInfo> {
+    let mut output: Vec<LatinTranslationInfo> = Vec::new();
+
+    for contraction in try_contractions(latin_word) {
+        let expanded_word = contraction.get_word();
+        let explanations = contraction.get_explanations();
+
+        let new_output = parse(&expanded_word, false, trace);
+
+        trace.start_stage("contractions", latin_word);
+        match new_output {
+            Some(mut new_output) => {
+                trace.accept(&expanded_word, &explanations.join("; "));
+                for word in new_output.iter_mut() {
+                    word.set_tricks(&explanations);
+                }
+                output.extend(new_output);
             }
+            None => trace.reject(&expanded_word, "expanded word was not found"),
         }
     }
 
-    output.unwrap_or(Vec::new())
+    output
 }
@@ -1,15 +1,23 @@
 use crate::dictionary_structures::dictionary_keys::PartOfSpeech;
-use crate::dictionary_structures::dictionary_values::{Inflection, LatinWordInfo, NValue, Stem};
+use crate::dictionary_structures::dictionary_values::{
+    Form, Inflection, LatinWordInfo, NValue, Stem,
+};
+use crate::translators::latin_to_english::trace::Trace;
 use crate::translators::latin_to_english::translator::lookup_stems;
 use crate::translators::latin_to_english::tricks::{try_medieval_tricks, TrickResult};
 use crate::translators::latin_to_english::utils::reduce;
 use crate::translators::latin_to_english::LatinTranslationInfo;
 use crate::utils::data::{get_latin_inflections, get_latin_stems, get_unique_latin_words};
+use crate::utils::type_translator::is_greek_n;
 
-pub fn parse(latin_word: &str, reduced: bool) -> Option<Vec<LatinTranslationInfo>> {
-    match find_form(latin_word, reduced) {
+pub fn parse(
+    latin_word: &str,
+    reduced: bool,
+    trace: &mut Trace,
+) -> Option<Vec<LatinTranslationInfo>> {
+    match find_form(latin_word, reduced, trace) {
         Some(form) => return Some(form),
-        None => match parse_unique_latin_words(latin_word) {
+        None => match parse_unique_latin_words(latin_word, trace) {
             Some(unique_word) => {
                 let mut translation = LatinTranslationInfo::new();
                 translation.word = unique_word;
@@ -20,19 +28,32 @@ pub fn parse(latin_word: &str, reduced: bool) -> Option<Vec<LatinTranslationInfo
     }
 }
 
-fn parse_unique_latin_words(latin_word: &str) -> Option<LatinWordInfo> {
+fn parse_unique_latin_words(latin_word: &str, trace: &mut Trace) -> Option<LatinWordInfo> {
     let unique_words = get_unique_latin_words();
 
+    trace.start_stage("unique words", latin_word);
     let latin_word_lower = latin_word.to_lowercase();
-    unique_words
+    let unique_word = unique_words
         .into_iter()
-        .find(|unique_word| unique_word.orth.to_lowercase() == latin_word_lower)
+        .find(|unique_word| unique_word.orth.to_lowercase() == latin_word_lower);
+
+    match unique_word {
+        Some(_) => trace.accept(latin_word, "found in the unique words list"),
+        None => trace.reject(latin_word, "not in the unique words list"),
+    }
+
+    unique_word
 }
 
-pub fn find_form(latin_word: &str, reduced: bool) -> Option<Vec<LatinTranslationInfo>> {
+pub fn find_form(
+    latin_word: &str,
+    reduced: bool,
+    trace: &mut Trace,
+) -> Option<Vec<LatinTranslationInfo>> {
     let latin_inflections: Vec<Inflection> = get_latin_inflections();
     let mut latin_word_inflections: Vec<Inflection> = Vec::new();
 
+    trace.start_stage("find form", latin_word);
     for inflection in latin_inflections {
         if latin_word.ends_with(inflection.ending.as_str()) {
             // if the longest inflection has been found, stop looking
@@ -41,21 +62,31 @@ pub fn find_form(latin_word: &str, reduced: bool) -> Option<Vec<LatinTranslation
             {
                 break;
             }
+            if trace.is_enabled() {
+                trace.accept(
+                    &format!("-{}", inflection.ending),
+                    &format!(
+                        "{} {}",
+                        inflection.pos.as_str(),
+                        clean_form(&inflection.form)
+                    ),
+                );
+            }
             latin_word_inflections.push(inflection);
         }
     }
 
-    let (stems, inflections) = check_stems(latin_word, &latin_word_inflections, false);
-    let mut output = lookup_stems(stems, inflections);
+    let (stems, inflections) = check_stems(latin_word, &latin_word_inflections, false, trace);
+    let mut output = lookup_stems(stems, inflections, trace);
 
     if output.is_none() && !reduced {
-        output = reduce(latin_word);
+        output = reduce(latin_word, trace);
     }
 
     //curebantur -> currebantur (needs to work on stem or word: cureb -> curreb)
     if output.is_none() {
-        let (stems, inflections) = check_stems(latin_word, &latin_word_inflections, true);
-        output = lookup_stems(stems, inflections);
+        let (stems, inflections) = check_stems(latin_word, &latin_word_inflections, true, trace);
+        output = lookup_stems(stems, inflections, trace);
     }
 
     output
@@ -65,11 +96,18 @@ fn check_stems(
     latin_word: &str,
     latin_word_inflections: &Vec<Inflection>,
     tricks: bool,
+    trace: &mut Trace,
 ) -> (Vec<Stem>, Vec<Inflection>) {
     let latin_stems = get_latin_stems();
     let mut matched_stems: Vec<Stem> = Vec::new();
     let mut inflections: Vec<Inflection> = Vec::new();
-    let mut found_inflection_forms: Vec<String> = Vec::new();
+    // the forms each entry was matched with, homographs can be matched with the same form, ex: liber (book, free)
+    let mut found_inflection_forms: Vec<(i32, String)> = Vec::new();
+
+    match tricks {
+        true => trace.start_stage("check stems (medieval tricks)", latin_word),
+        false => trace.start_stage("check stems", latin_word),
+    }
 
     for inflection in latin_word_inflections {
         //TODO: if the inflection.ending is "" and word cant take inflection endings, continue
@@ -90,8 +128,16 @@ fn check_stems(
             false => word_stem.to_string(),
         };
 
+        let mut stem_exists = false;
         for stem in &latin_stems {
-            if word_stem == stem.orth {
+            // greek names are stored capitalized, ex: Aene, Anchis, Pericl
+            let greek_stem = stem.n.as_deref().is_some_and(|n| is_greek_n(n, stem.pos));
+            if word_stem == stem.orth || (greek_stem && word_stem.eq_ignore_ascii_case(&stem.orth))
+            {
+                stem_exists = true;
+                // the trace messages are only built when the trace is recorded
+                let candidate = || format!("{}.{} ({})", stem.orth, inflection.ending, stem.wid);
+
                 if inflection.pos == stem.pos
                     || (inflection.pos == PartOfSpeech::Participle
                         && stem.pos == PartOfSpeech::Verb)
@@ -113,8 +159,19 @@ fn check_stems(
                         }
                     };
 
+                    let n_mismatch = || {
+                        format!(
+                            "n {} of the stem does not match n {} of the inflection",
+                            serde_json::to_string(n_from_stem).unwrap_or_default(),
+                            serde_json::to_string(n_from_inflection).unwrap_or_default()
+                        )
+                    };
+
                     //TODO: Weird issue here where some words get inflections but should not (cur)
                     if n_from_stem.len() == 1 && n_from_stem[0] != n_from_inflection[0] {
+                        if trace.is_enabled() {
+                            trace.reject(&candidate(), &n_mismatch());
+                        }
                         continue;
                     }
 
@@ -122,21 +179,37 @@ fn check_stems(
                         if n_from_inflection[0] != n_from_stem[0]
                             && n_from_inflection[0] != NValue::Integer(0)
                         {
+                            if trace.is_enabled() {
+                                trace.reject(&candidate(), &n_mismatch());
+                            }
                             continue;
                         }
 
                         if n_from_inflection[1] != n_from_stem[1]
                             && n_from_inflection[1] != NValue::Integer(0)
                         {
+                            if trace.is_enabled() {
+                                trace.reject(&candidate(), &n_mismatch());
+                            }
                             continue;
                         }
                     }
 
-                    if found_inflection_forms.contains(&inflection.form.as_str()) {
+                    let found_form = (stem.wid, inflection.form.as_str());
+                    if found_inflection_forms.contains(&found_form) {
+                        if trace.is_enabled() {
+                            trace.reject(
+                                &candidate(),
+                                &format!(
+                                    "inflection form {} was already matched for this entry",
+                                    clean_form(&inflection.form)
+                                ),
+                            );
+                        }
                         continue;
                     }
 
-                    found_inflection_forms.push(inflection.form.as_str());
+                    found_inflection_forms.push(found_form);
 
                     for stem_inflection in &inflections {
                         if stem_inflection.pos == inflection.pos
@@ -148,12 +221,50 @@ fn check_stems(
                             break;
                         }
                     }
+                    if trace.is_enabled() {
+                        trace.accept(
+                            &candidate(),
+                            &format!(
+                                "{} stem takes {} {}",
+                                stem.pos.as_str(),
+                                inflection.pos.as_str(),
+                                clean_form(&inflection.form)
+                            ),
+                        );
+                    }
                     matched_stems.push(stem.clone());
-                    inflections.push(inflection.clone());
+                    if !inflections.iter().any(|found| {
+                        found.ending == inflection.ending && found.form == inflection.form
+                    }) {
+                        inflections.push(inflection.clone());
+                    }
+                } else if trace.is_enabled() {
+                    trace.reject(
+                        &candidate(),
+                        &format!(
+                            "{} stem does not match {} inflection",
+                            stem.pos.as_str(),
+                            inflection.pos.as_str()
+                        ),
+                    );
                 }
             }
         }
+
+        if !stem_exists && trace.is_enabled() {
+            trace.reject(
+                &format!("{}.{}", word_stem, inflection.ending),
+                &format!("no stem '{}' in the dictionary", word_stem),
+            );
+        }
     }
 
     (matched_stems, inflections)
 }
+
+fn clean_form(form: &Form) -> String {
+    form.as_str()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
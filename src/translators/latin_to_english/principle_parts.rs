@@ -0,0 +1,89 @@
+use crate::dictionary_structures::dictionary_values::Form;
+use crate::translators::latin_to_english::trace::Trace;
+use crate::translators::latin_to_english::translate_latin_to_english;
+use crate::translators::Structure;
+use serde::Serialize;
+
+/**
+ * The principle parts of one dictionary entry the word can come from.
+ * Entries without a known pattern have an error instead of parts.
+ */
+#[derive(Debug, Serialize)]
+pub struct PrincipleParts {
+    pub id: i32,
+    pub pos: String,
+    pub form: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WordPrincipleParts {
+    pub word: String,
+    pub entries: Vec<PrincipleParts>,
+}
+
+impl WordPrincipleParts {
+    pub fn display(&self) {
+        println!("{}:", self.word);
+
+        if self.entries.is_empty() {
+            println!("No definitions found");
+        }
+
+        for entry in &self.entries {
+            let description = format!("{} {}", entry.pos, entry.form);
+            match (&entry.parts, &entry.error) {
+                (Some(parts), _) => println!("{}: {}", description.trim(), parts.join(", ")),
+                (None, Some(error)) => println!("{}: {}", description.trim(), error),
+                (None, None) => {}
+            }
+        }
+        println!();
+    }
+}
+
+/**
+ * Finds the dictionary entries of a latin word, and generates the principle parts of each.
+ * ex: hortatur -> hortor, hortari, hortatus sum
+ */
+pub fn find_principle_parts(latin_word: &str, tricks: bool) -> WordPrincipleParts {
+    let definitions = translate_latin_to_english(latin_word, tricks, &mut Trace::new(false));
+    let mut entries: Vec<PrincipleParts> = Vec::new();
+
+    for definition in definitions {
+        let mut word = definition.word;
+        if entries.iter().any(|entry| entry.id == word.id) {
+            continue;
+        }
+
+        let principle_parts = word.principle_parts();
+
+        word.form
+            .str_form_to_long_form(word.pos, Structure::LatinWordInfo);
+        let form = match &word.form {
+            Form::StrForm(form) => form.to_string(),
+            Form::LongForm(form) => form.as_clean_str(),
+        };
+
+        let (parts, error) = match principle_parts {
+            Ok(parts) => (Some(parts), None),
+            Err(error) => (None, Some(error)),
+        };
+
+        entries.push(PrincipleParts {
+            id: word.id,
+            pos: word.pos.as_str().to_string(),
+            form,
+            parts,
+            error,
+        });
+    }
+
+    WordPrincipleParts {
+        word: latin_word.to_string(),
+        entries,
+    }
+}
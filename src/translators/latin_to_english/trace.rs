@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+pub enum TraceStatus {
+    #[serde(rename = "considered")]
+    Considered,
+    #[serde(rename = "accepted")]
+    Accepted,
+    #[serde(rename = "rejected")]
+    Rejected,
+}
+
+impl TraceStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TraceStatus::Considered => "considered",
+            TraceStatus::Accepted => "accepted",
+            TraceStatus::Rejected => "rejected",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TraceCandidate {
+    pub candidate: String,
+    pub status: TraceStatus,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TraceStage {
+    pub stage: String,
+    pub input: String,
+    pub candidates: Vec<TraceCandidate>,
+}
+
+/**
+ * Records every stage of the latin analysis pipeline, and the candidates that were
+ * considered, accepted or rejected in it.
+ * A disabled trace ignores everything, callers check is_enabled before building their messages
+ * so the pipeline does not format them when --explain is off.
+ */
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Trace {
+    #[serde(skip)]
+    enabled: bool,
+    pub stages: Vec<TraceStage>,
+}
+
+impl Trace {
+    pub fn new(enabled: bool) -> Trace {
+        Trace {
+            enabled,
+            stages: Vec::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn start_stage(&mut self, stage: &str, input: &str) {
+        if !self.enabled {
+            return;
+        }
+
+        self.stages.push(TraceStage {
+            stage: stage.to_string(),
+            input: input.to_string(),
+            candidates: Vec::new(),
+        });
+    }
+
+    pub fn consider(&mut self, candidate: &str, reason: &str) {
+        self.record(candidate, TraceStatus::Considered, reason);
+    }
+
+    pub fn accept(&mut self, candidate: &str, reason: &str) {
+        self.record(candidate, TraceStatus::Accepted, reason);
+    }
+
+    pub fn reject(&mut self, candidate: &str, reason: &str) {
+        self.record(candidate, TraceStatus::Rejected, reason);
+    }
+
+    fn record(&mut self, candidate: &str, status: TraceStatus, reason: &str) {
+        if !self.enabled {
+            return;
+        }
+
+        if self.stages.is_empty() {
+            self.start_stage("unnamed", "");
+        }
+
+        self.stages
+            .last_mut()
+            .unwrap()
+            .candidates
+            .push(TraceCandidate {
+                candidate: candidate.to_string(),
+                status,
+                reason: reason.to_string(),
+            });
+    }
+
+    pub fn display(&self) {
+        println!("trace:");
+        for stage in &self.stages {
+            println!("  [{}] {}", stage.stage, stage.input);
+            if stage.candidates.is_empty() {
+                println!("    (no candidates)");
+            }
+            for candidate in &stage.candidates {
+                println!(
+                    "    {:<10} {:<20} {}",
+                    candidate.status.as_str(),
+                    candidate.candidate,
+                    candidate.reason
+                );
+            }
+        }
+        println!();
+    }
+}
@@ -1,5 +1,6 @@
 use crate::dictionary_structures::dictionary_keys::PartOfSpeech;
 use crate::dictionary_structures::dictionary_values::{Inflection, LatinWordInfo, Part, Stem};
+use crate::translators::latin_to_english::trace::Trace;
 use crate::translators::latin_to_english::utils::add_stem_to_word;
 use crate::translators::latin_to_english::LatinTranslationInfo;
 use crate::utils::data::get_latin_dictionary;
@@ -8,7 +9,12 @@ use std::collections::HashMap;
 pub fn lookup_stems(
     stems: Vec<Stem>,
     inflections: Vec<Inflection>,
+    trace: &mut Trace,
 ) -> Option<Vec<LatinTranslationInfo>> {
+    if stems.is_empty() {
+        return None;
+    }
+
     let latin_dictionary = get_latin_dictionary();
     let mut output: Vec<LatinTranslationInfo> = Vec::new();
 
@@ -17,21 +23,36 @@ pub fn lookup_stems(
         .map(|word| (word.id, word))
         .collect();
 
+    if trace.is_enabled() {
+        trace.start_stage("lookup stems", &format!("{} matched stems", stems.len()));
+    }
     for stem in stems {
         let dict_word = latin_words_map.get(&stem.wid);
 
+        if dict_word.is_none() && trace.is_enabled() {
+            trace.reject(
+                &stem.orth,
+                &format!("no dictionary entry with id {}", stem.wid),
+            );
+        }
+
         if let Some(latin_word) = dict_word {
-            let word_is_in_output = output
-                .iter()
-                .any(|x| x.word.id == latin_word.id || x.word.orth == latin_word.orth);
+            let word_is_in_output = output.iter().any(|x| x.word.id == latin_word.id);
 
             // if the word is already in the output add the stem to it
             if word_is_in_output {
-                let matching_word = output
-                    .iter_mut()
-                    .find(|x| x.word.id == latin_word.id || x.word.orth == latin_word.orth);
+                let matching_word = output.iter_mut().find(|x| x.word.id == latin_word.id);
 
                 if let Some(word) = matching_word {
+                    if trace.is_enabled() {
+                        trace.consider(
+                            &stem.orth,
+                            &format!(
+                                "merged into entry {} ({}) already in the output",
+                                word.word.id, word.word.orth
+                            ),
+                        );
+                    }
                     add_stem_to_word(stem, Some(word));
                 }
             } else {
@@ -55,7 +76,18 @@ pub fn lookup_stems(
                             .collect();
                     }
                 } else {
-                    new_inflections = inflections.clone();
+                    // endings that go on one stem only, ex: meli.or but not bon.or
+                    new_inflections = inflections
+                        .iter()
+                        .filter(|inflection| match inflection.stem_key {
+                            Some(stem_key) if inflection.pos == latin_word.pos => latin_word
+                                .parts
+                                .get(stem_key as usize - 1)
+                                .is_some_and(|part| part.eq_ignore_ascii_case(&stem.orth)),
+                            _ => true,
+                        })
+                        .cloned()
+                        .collect();
                 }
 
                 let mut new_word = LatinTranslationInfo::new();
@@ -71,6 +103,13 @@ pub fn lookup_stems(
                     }
                 }
 
+                if trace.is_enabled() {
+                    trace.accept(
+                        &stem.orth,
+                        &format!("dictionary entry {} ({})", latin_word.id, latin_word.orth),
+                    );
+                }
+
                 new_word.word.set_word(&latin_word);
                 new_word.stem = stem;
                 new_word.inflections = Some(new_inflections);
@@ -0,0 +1,170 @@
+use crate::translators::latin_to_english::tricks::TrickResult;
+use crate::utils::is_vowel;
+
+const APOSTROPHES: [char; 2] = ['\'', '’'];
+
+// endings that can be lost when a word is elided before a vowel, ex: atqu' ego -> atque ego
+const ELIDED_ENDINGS: [&str; 8] = ["e", "a", "i", "o", "u", "um", "am", "em"];
+
+// contracted perfect endings and the full endings they stand for, longest first
+const PERFECT_SYNCOPES: [(&str, &str); 14] = [
+    ("assent", "avissent"),
+    ("issent", "ivissent"),
+    ("assem", "avissem"),
+    ("issem", "ivissem"),
+    ("astis", "avistis"),
+    ("istis", "ivistis"),
+    ("arunt", "averunt"),
+    ("asti", "avisti"),
+    ("isti", "ivisti"),
+    ("asse", "avisse"),
+    ("isse", "ivisse"),
+    ("xtis", "xistis"),
+    ("xti", "xisti"),
+    ("arim", "averim"),
+];
+
+/**
+ * A piece of a token that was written with an apostrophe.
+ * Candidates are the ways the missing letters can be restored, the explanation is added to the tricks of every analysis.
+ */
+pub struct ContractedWord {
+    pub word: String,
+    pub candidates: Vec<String>,
+    pub explanation: Option<String>,
+}
+
+/**
+ * Splits a token on its apostrophes, ex: atqu'ego -> atqu' + ego, tibi'st -> tibi + 'st
+ * A token without an apostrophe is returned as is.
+ */
+pub fn split_elisions(token: &str) -> Vec<ContractedWord> {
+    let segments: Vec<&str> = token.split(&APOSTROPHES[..]).collect();
+
+    if segments.len() == 1 {
+        return vec![ContractedWord {
+            word: token.to_string(),
+            candidates: vec![token.to_string()],
+            explanation: None,
+        }];
+    }
+
+    let mut words: Vec<ContractedWord> = Vec::new();
+    for (index, segment) in segments.iter().enumerate() {
+        if segment.is_empty() {
+            continue;
+        }
+
+        let is_prodelided = index > 0 && (*segment == "st" || *segment == "s");
+        let is_elided = index < segments.len() - 1
+            && !segments
+                .get(index + 1)
+                .is_some_and(|next| *next == "st" || *next == "s");
+
+        if is_prodelided {
+            let full_word = if *segment == "st" { "est" } else { "es" };
+            words.push(ContractedWord {
+                word: format!("'{}", segment),
+                candidates: vec![full_word.to_string()],
+                explanation: Some(format!(
+                    "'{}' is '{}' with its initial 'e' dropped after the word before it (prodelision)",
+                    segment, full_word
+                )),
+            });
+        } else if is_elided {
+            words.push(ContractedWord {
+                word: format!("{}'", segment),
+                candidates: ELIDED_ENDINGS
+                    .iter()
+                    .map(|ending| format!("{}{}", segment, ending))
+                    .collect(),
+                explanation: Some(format!(
+                    "'{}' has its final vowel or -m elided before the next word",
+                    segment
+                )),
+            });
+        } else {
+            words.push(ContractedWord {
+                word: segment.to_string(),
+                candidates: vec![segment.to_string()],
+                explanation: None,
+            });
+        }
+    }
+
+    words
+}
+
+/**
+ * Expands contractions written as one word, ex: amatumst -> amatum (est), dixti -> dixisti, amarunt -> amaverunt
+ * Every expansion that applies is returned, the analysis keeps the ones that are found.
+ */
+pub fn try_contractions(word: &str) -> Vec<TrickResult> {
+    let mut results: Vec<TrickResult> = Vec::new();
+
+    results.extend(try_prodelision(word));
+
+    for (contracted, full) in PERFECT_SYNCOPES {
+        if word.len() > contracted.len() + 1 && word.ends_with(contracted) {
+            let new_word = format!("{}{}", &word[..word.len() - contracted.len()], full);
+            results.push(TrickResult::Found(
+                new_word,
+                vec![format!(
+                    "Syncopated perfect '-{}' may stand for '-{}'",
+                    contracted, full
+                )],
+            ));
+            break;
+        }
+    }
+
+    // -ere for -erunt only follows a perfect stem, ex: amavere, fuere, dixere
+    if word.len() > 4 && word.ends_with("ere") {
+        let stem_end = word[..word.len() - 3].chars().last().unwrap_or_default();
+        if ['v', 'u', 'x', 's', 'i'].contains(&stem_end) {
+            results.push(TrickResult::Found(
+                format!("{}erunt", &word[..word.len() - 3]),
+                vec![String::from(
+                    "Perfect '-ere' may stand for the 3rd person plural '-erunt'",
+                )],
+            ));
+        }
+    }
+
+    results
+}
+
+// est written into the word before it, ex: bonumst -> bonum est, amatast -> amata est, opust -> opus est
+fn try_prodelision(word: &str) -> Vec<TrickResult> {
+    let mut results: Vec<TrickResult> = Vec::new();
+
+    if word.len() < 4 || !word.ends_with("st") {
+        return results;
+    }
+
+    let host = &word[..word.len() - 2];
+    let host_end = host.chars().last().unwrap_or_default();
+    if is_vowel(host_end) || host_end == 'm' {
+        results.push(TrickResult::Found(
+            host.to_string(),
+            vec![format!(
+                "'{}' may be '{} est' with the 'e' of 'est' dropped (prodelision)",
+                word, host
+            )],
+        ));
+    }
+
+    // the s of the word is shared with est
+    if word.ends_with("ust") || word.ends_with("ist") {
+        let host = &word[..word.len() - 1];
+        results.push(TrickResult::Found(
+            host.to_string(),
+            vec![format!(
+                "'{}' may be '{} est' with the 'e' of 'est' dropped (prodelision)",
+                word, host
+            )],
+        ));
+    }
+
+    results
+}
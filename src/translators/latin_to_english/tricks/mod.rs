@@ -1,3 +1,4 @@
+pub mod contractions;
 pub mod trick_lists;
 pub mod word_mods;
 
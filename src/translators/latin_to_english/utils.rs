@@ -1,10 +1,17 @@
-use crate::dictionary_structures::dictionary_values::{Modifier, ModifierType, Stem};
+use crate::dictionary_structures::dictionary_keys::PartOfSpeech;
+use crate::dictionary_structures::dictionary_values::{
+    Form, Inflection, LatinWordInfo, Modifier, ModifierType, NValue, Stem,
+};
 use crate::translators::latin_to_english::parser::find_form;
+use crate::translators::latin_to_english::trace::Trace;
 use crate::translators::latin_to_english::LatinTranslationInfo;
 use crate::utils::data::{
+    get_latin_dictionary, get_latin_enclitic_exceptions, get_latin_inflections,
     get_latin_not_packons, get_latin_packons, get_latin_prefixes, get_latin_suffixes,
-    get_latin_tackons,
+    get_latin_tackons, get_latin_tickons,
 };
+use crate::utils::sorting::{sort_entries, SortBy, SortKey, Sortable};
+use std::collections::HashMap;
 
 pub fn add_stem_to_word(matched_stem: Stem, matching_word: Option<&mut LatinTranslationInfo>) {
     if let Some(word) = matching_word {
@@ -17,87 +24,556 @@ pub fn add_stem_to_word(matched_stem: Stem, matching_word: Option<&mut LatinTran
     }
 }
 
-pub fn reduce(latin_word: &str) -> Option<Vec<LatinTranslationInfo>> {
-    let mut modifiers: Vec<Modifier> = Vec::new();
-    let latin_prefixes = get_latin_prefixes();
-    let latin_suffixes = get_latin_suffixes();
+/**
+ * A prefix rule from latin_prefixes.json.
+ * The connect letter is the letter the prefix must be followed by, ex: "af f" is ad- before f (affero).
+ */
+pub struct PrefixRule {
+    pub affix: String,
+    pub connect: Option<char>,
+    pub pos: Option<PartOfSpeech>,
+    pub modifier: Modifier,
+}
+
+/**
+ * A suffix rule from latin_suffixes.json, ex: "N 2 ADJ 1 1 POS 0".
+ * The suffix attaches to stem 2 of a noun and makes a 1st/2nd declension adjective, the new stem is stem 0 (1 and 2).
+ * A connect letter is the letter the base stem must end with.
+ */
+pub struct SuffixRule {
+    pub affix: String,
+    pub connect: Option<char>,
+    pub base_pos: Option<PartOfSpeech>,
+    pub base_key: usize,
+    pub derived_pos: PartOfSpeech,
+    pub derived_form: String,
+    pub derived_n: Vec<NValue>,
+    pub derived_key: usize,
+    pub modifier: Modifier,
+}
 
-    let mut stripped_latin_word = latin_word.to_string();
-    latin_prefixes.iter().for_each(|prefix| {
-        if stripped_latin_word.starts_with(prefix.orth.as_str()) {
-            stripped_latin_word = stripped_latin_word
-                .trim_start_matches(prefix.orth.as_str())
-                .to_string();
+pub fn prefix_rules() -> Vec<PrefixRule> {
+    get_latin_prefixes()
+        .into_iter()
+        .filter_map(parse_prefix_rule)
+        .collect()
+}
 
-            let mut modifier = Modifier::new();
-            modifier.set_orth(&prefix.orth);
-            modifier.set_pos(prefix.pos);
-            modifier.set_senses(&prefix.senses);
-            modifier.set_modifier(ModifierType::Prefix);
+pub fn suffix_rules() -> Vec<SuffixRule> {
+    get_latin_suffixes()
+        .into_iter()
+        .filter_map(parse_suffix_rule)
+        .collect()
+}
 
-            modifiers.push(modifier);
-        }
-    });
+pub fn reduce(latin_word: &str, trace: &mut Trace) -> Option<Vec<LatinTranslationInfo>> {
+    let prefix_rules = prefix_rules();
+    let suffix_rules = suffix_rules();
 
-    latin_suffixes.iter().for_each(|suffix| {
-        if stripped_latin_word.ends_with(suffix.orth.as_str()) {
-            stripped_latin_word = stripped_latin_word
-                .trim_end_matches(suffix.orth.as_str())
-                .to_string();
+    trace.start_stage("reduce", latin_word);
 
-            let mut modifier = Modifier::new();
-            modifier.set_orth(&suffix.orth);
-            modifier.set_pos(suffix.pos);
-            modifier.set_senses(&suffix.senses);
-            modifier.set_modifier(ModifierType::Suffix);
+    // every way to remove one prefix, the unprefixed word is tried with suffixes only
+    let mut remainders: Vec<(Option<&PrefixRule>, String)> = vec![(None, latin_word.to_string())];
+    for prefix in &prefix_rules {
+        let remainder = match latin_word.strip_prefix(prefix.affix.as_str()) {
+            Some(remainder) => remainder,
+            None => continue,
+        };
 
-            modifiers.push(modifier);
+        if remainder.is_empty() {
+            continue;
         }
-    });
 
-    if stripped_latin_word == latin_word || stripped_latin_word.len() == 0 || modifiers.len() == 0 {
-        return None;
+        if let Some(connect) = prefix.connect {
+            if !remainder.starts_with(connect) {
+                continue;
+            }
+        }
+
+        if trace.is_enabled() {
+            let reason = match prefix.connect {
+                Some(connect) => format!("prefix before '{}', leaving '{}'", connect, remainder),
+                None => format!("prefix stripped, leaving '{}'", remainder),
+            };
+            trace.consider(&format!("{}-", prefix.affix), &reason);
+        }
+        remainders.push((Some(prefix), remainder.to_string()));
     }
 
-    let mut output = find_form(&stripped_latin_word, true);
+    let mut output: Vec<LatinTranslationInfo> = Vec::new();
 
-    if output.is_some() {
-        for word in output.as_mut().unwrap() {
-            word.word.set_modifiers(modifiers.clone());
+    for (prefix, remainder) in &remainders {
+        for derived in derive_with_suffixes(latin_word, *prefix, remainder, &suffix_rules, trace) {
+            push_decomposition(&mut output, derived);
         }
 
-        output
-    } else {
+        let prefix = match prefix {
+            Some(prefix) => prefix,
+            None => continue,
+        };
+
+        // a prefix does not change the part of speech, so the rest of the word is looked up as is
+        let found = match find_form(remainder, true, trace) {
+            Some(found) => found,
+            None => continue,
+        };
+
+        trace.start_stage("reduce", latin_word);
+        for mut word in found {
+            // the trace messages are only built when the trace is recorded
+            let candidate = || format!("{}-{} ({})", prefix.affix, remainder, word.word.id);
+            if !pos_matches(prefix.pos, word.word.pos) {
+                if trace.is_enabled() {
+                    trace.reject(
+                        &candidate(),
+                        &format!(
+                            "prefix {}- does not attach to a {}",
+                            prefix.affix,
+                            word.word.pos.as_str()
+                        ),
+                    );
+                }
+                continue;
+            }
+
+            if trace.is_enabled() {
+                trace.accept(
+                    &candidate(),
+                    &format!("{}- added to {}", prefix.affix, word.word.orth),
+                );
+            }
+            let mut modifiers = vec![prefix.modifier.clone()];
+            modifiers.extend(word.word.modifiers.take().unwrap_or_default());
+            word.word.set_modifiers(modifiers);
+            push_decomposition(&mut output, word);
+        }
+    }
+
+    if output.is_empty() {
+        trace.reject(
+            latin_word,
+            "no prefix or suffix rule gives a word in the dictionary",
+        );
         None
+    } else {
+        Some(output)
+    }
+}
+
+/**
+ * Removes the inflection and a suffix from the word, and looks the base up with the part of speech
+ * and stem the suffix rule asks for.
+ * The analysis gets the part of speech and form the suffix makes, with the senses of the base.
+ */
+fn derive_with_suffixes(
+    latin_word: &str,
+    prefix: Option<&PrefixRule>,
+    remainder: &str,
+    suffix_rules: &[SuffixRule],
+    trace: &mut Trace,
+) -> Vec<LatinTranslationInfo> {
+    let mut candidates: Vec<(&SuffixRule, String, Inflection)> = Vec::new();
+
+    for inflection in get_latin_inflections() {
+        let word_stem = match remainder.strip_suffix(inflection.ending.as_str()) {
+            Some(word_stem) => word_stem,
+            None => continue,
+        };
+
+        for suffix in suffix_rules {
+            let base = match word_stem.strip_suffix(suffix.affix.as_str()) {
+                Some(base) if base.len() > 1 => base,
+                _ => continue,
+            };
+
+            if let Some(connect) = suffix.connect {
+                if !base.ends_with(connect) {
+                    continue;
+                }
+            }
+
+            if !pos_matches(Some(suffix.derived_pos), inflection.pos)
+                || !n_matches(
+                    &suffix.derived_n,
+                    inflection.n.as_deref().unwrap_or_default(),
+                )
+            {
+                continue;
+            }
+
+            candidates.push((suffix, base.to_string(), inflection.clone()));
+        }
+    }
+
+    if candidates.is_empty() {
+        return Vec::new();
     }
+
+    let latin_dictionary = get_latin_dictionary();
+    let mut words_by_part: HashMap<&str, Vec<&LatinWordInfo>> = HashMap::new();
+    for word in &latin_dictionary {
+        for part in &word.parts {
+            words_by_part.entry(part.as_str()).or_default().push(word);
+        }
+    }
+
+    let mut output: Vec<LatinTranslationInfo> = Vec::new();
+    for (suffix, base, inflection) in candidates {
+        let candidate = || format!("{}+{}.{}", base, suffix.affix, inflection.ending);
+        let base_words: Vec<&LatinWordInfo> = words_by_part
+            .get(base.as_str())
+            .map(|words| {
+                words
+                    .iter()
+                    .filter(|word| {
+                        pos_matches(suffix.base_pos, word.pos)
+                            && has_stem(word, suffix.base_key, &base)
+                    })
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if base_words.is_empty() {
+            if trace.is_enabled() {
+                trace.reject(
+                    &candidate(),
+                    &format!(
+                        "no {} with stem {} '{}' in the dictionary",
+                        suffix.base_pos.map_or("word", |pos| pos.as_str()),
+                        suffix.base_key,
+                        base
+                    ),
+                );
+            }
+            continue;
+        }
+
+        if let Some(prefix) = prefix {
+            if !pos_matches(prefix.pos, suffix.derived_pos) {
+                if trace.is_enabled() {
+                    trace.reject(
+                        &candidate(),
+                        &format!(
+                            "prefix {}- does not attach to a {}",
+                            prefix.affix,
+                            suffix.derived_pos.as_str()
+                        ),
+                    );
+                }
+                continue;
+            }
+        }
+
+        let stem_orth = &latin_word[..latin_word.len() - inflection.ending.len()];
+        for base_word in base_words {
+            if trace.is_enabled() {
+                trace.accept(
+                    &candidate(),
+                    &format!(
+                        "{} {} ({}) becomes {} {}",
+                        base_word.pos.as_str(),
+                        base_word.orth,
+                        base_word.id,
+                        suffix.derived_pos.as_str(),
+                        suffix.derived_form
+                    ),
+                );
+            }
+
+            let mut derived = LatinTranslationInfo::new();
+            derived.set_word(&derive_word(base_word, suffix, stem_orth));
+            derived.set_stem(&derive_stem(base_word, suffix, stem_orth));
+            derived.set_inflections(vec![inflection.clone()]);
+
+            let mut modifiers: Vec<Modifier> = Vec::new();
+            if let Some(prefix) = prefix {
+                modifiers.push(prefix.modifier.clone());
+            }
+            modifiers.push(suffix.modifier.clone());
+            derived.word.set_modifiers(modifiers);
+
+            output.push(derived);
+        }
+    }
+
+    output
+}
+
+/**
+ * Adds a decomposition to the output, merging the inflections of one already found the same way.
+ */
+fn push_decomposition(output: &mut Vec<LatinTranslationInfo>, decomposition: LatinTranslationInfo) {
+    let key = decomposition_key(&decomposition);
+    match output
+        .iter_mut()
+        .find(|word| decomposition_key(word) == key)
+    {
+        Some(word) => {
+            let mut inflections = word.inflections.take().unwrap_or_default();
+            for inflection in decomposition.inflections.unwrap_or_default() {
+                if !inflections
+                    .iter()
+                    .any(|found| found.ending == inflection.ending && found.form == inflection.form)
+                {
+                    inflections.push(inflection);
+                }
+            }
+            word.set_inflections(inflections);
+        }
+        None => output.push(decomposition),
+    }
+}
+
+fn decomposition_key(word: &LatinTranslationInfo) -> String {
+    let modifiers: Vec<String> = word
+        .word
+        .modifiers
+        .iter()
+        .flatten()
+        .map(|modifier| format!("{}:{}", modifier.modifier.as_str(), modifier.orth))
+        .collect();
+
+    format!(
+        "{} {} {} {}",
+        word.word.id,
+        word.word.pos.as_str(),
+        word.word.form.as_str(),
+        modifiers.join(",")
+    )
 }
 
-pub fn split_enclitic(latin_word: &str) -> (String, Vec<Modifier>) {
+fn derive_word(base_word: &LatinWordInfo, suffix: &SuffixRule, stem_orth: &str) -> LatinWordInfo {
+    let part_count = match suffix.derived_pos {
+        PartOfSpeech::Noun => 2,
+        PartOfSpeech::Adjective | PartOfSpeech::Verb | PartOfSpeech::Numeral => 4,
+        _ => 1,
+    };
+
+    let mut parts = vec!["zzz".to_string(); part_count];
+    match suffix.derived_key {
+        // stem 0 is used for both stem 1 and stem 2
+        0 => parts
+            .iter_mut()
+            .take(2)
+            .for_each(|part| *part = stem_orth.to_string()),
+        key if key <= part_count => parts[key - 1] = stem_orth.to_string(),
+        _ => parts[0] = stem_orth.to_string(),
+    }
+
+    let mut word = base_word.clone();
+    word.set_orth(stem_orth);
+    word.set_parts(parts);
+    word.set_pos(suffix.derived_pos);
+    word.set_form(Form::StrForm(suffix.derived_form.clone()));
+    word.set_n(suffix.derived_n.clone());
+    word
+}
+
+fn derive_stem(base_word: &LatinWordInfo, suffix: &SuffixRule, stem_orth: &str) -> Stem {
+    let mut stem = Stem::new();
+    stem.set_orth(stem_orth);
+    stem.set_pos(suffix.derived_pos);
+    stem.set_form(Form::StrForm(suffix.derived_form.clone()));
+    stem.set_n(suffix.derived_n.clone());
+    stem.set_wid(base_word.id);
+    stem
+}
+
+/**
+ * Stem 0 in the rules means the first two stems, which are the same for the words it is used with.
+ */
+pub fn has_stem(word: &LatinWordInfo, key: usize, stem: &str) -> bool {
+    match key {
+        0 => word.parts.iter().take(2).any(|part| part == stem),
+        key => word.parts.get(key - 1).is_some_and(|part| part == stem),
+    }
+}
+
+// X in the rules means any part of speech, participles are formed from verbs
+pub fn pos_matches(rule_pos: Option<PartOfSpeech>, pos: PartOfSpeech) -> bool {
+    match rule_pos {
+        None => true,
+        Some(PartOfSpeech::Verb) => pos == PartOfSpeech::Verb || pos == PartOfSpeech::Participle,
+        Some(rule_pos) => rule_pos == pos,
+    }
+}
+
+// 0 matches any declension or conjugation
+fn n_matches(rule_n: &[NValue], inflection_n: &[NValue]) -> bool {
+    if rule_n.len() < 2 || inflection_n.len() < 2 {
+        return true;
+    }
+
+    let rule_n = (rule_n[0].get_n_value_1(), rule_n[1].get_n_value_2());
+    let inflection_n = (
+        inflection_n[0].get_n_value_1(),
+        inflection_n[1].get_n_value_2(),
+    );
+
+    (rule_n.0 == 0 || inflection_n.0 == 0 || rule_n.0 == inflection_n.0)
+        && (rule_n.1 == 0 || inflection_n.1 == 0 || rule_n.1 == inflection_n.1)
+}
+
+/**
+ * Splits an orth like "af f" or "udin  t" into the affix and its connect letter.
+ * Anything after -- is a note.
+ */
+fn parse_affix_orth(orth: &str) -> Option<(String, Option<char>)> {
+    let orth = orth.split("--").next().unwrap_or_default();
+    let mut tokens = orth.split_whitespace();
+    let affix = tokens.next()?.to_string();
+    let connect = tokens.next().and_then(|connect| connect.chars().next());
+
+    Some((affix, connect))
+}
+
+fn parse_rule_pos(key: &str) -> Option<PartOfSpeech> {
+    match key {
+        "X" => None,
+        key => Some(PartOfSpeech::dict_key_to_part_of_speech(key)),
+    }
+}
+
+fn parse_prefix_rule(prefix: Modifier) -> Option<PrefixRule> {
+    let (affix, connect) = parse_affix_orth(&prefix.orth)?;
+    let form = prefix.form.as_ref()?.as_str();
+    let pos_key = form.split_whitespace().next()?;
+    if pos_key.starts_with("--") {
+        return None;
+    }
+    let pos = parse_rule_pos(pos_key);
+
+    let mut modifier = Modifier::new();
+    modifier.set_orth(&affix);
+    modifier.set_pos(pos.unwrap_or(PartOfSpeech::Unknown));
+    modifier.set_form(Form::StrForm(
+        form.split_whitespace().collect::<Vec<&str>>().join(" "),
+    ));
+    modifier.set_senses(&prefix.senses);
+    modifier.set_modifier(ModifierType::Prefix);
+    modifier.set_id(prefix.id);
+
+    Some(PrefixRule {
+        affix,
+        connect,
+        pos,
+        modifier,
+    })
+}
+
+// ROOT_POS root_key TARGET_POS target_form... target_key
+fn parse_suffix_rule(suffix: Modifier) -> Option<SuffixRule> {
+    let (affix, connect) = parse_affix_orth(&suffix.orth)?;
+    let form = suffix.form.as_ref()?.as_str();
+    let tokens: Vec<&str> = form.split_whitespace().collect();
+    if tokens.len() < 4 {
+        return None;
+    }
+
+    let base_pos = parse_rule_pos(tokens[0]);
+    let base_key: usize = tokens[1].parse().ok()?;
+    let derived_pos = parse_rule_pos(tokens[2])?;
+    let derived_key: usize = tokens[tokens.len() - 1].parse().ok()?;
+    let derived_form_tokens = &tokens[3..tokens.len() - 1];
+    let derived_form = derived_form_tokens.join(" ").to_uppercase();
+
+    let derived_n = match (
+        derived_form_tokens
+            .first()
+            .and_then(|n| n.parse::<i8>().ok()),
+        derived_form_tokens
+            .get(1)
+            .and_then(|n| n.parse::<i8>().ok()),
+    ) {
+        (Some(n1), Some(n2)) => vec![NValue::Integer(n1), NValue::Integer(n2)],
+        _ => derived_form_tokens
+            .first()
+            .map(|n| vec![NValue::String(n.to_string())])
+            .unwrap_or_default(),
+    };
+
+    let mut modifier = Modifier::new();
+    modifier.set_orth(&affix);
+    modifier.set_pos(derived_pos);
+    modifier.set_form(Form::StrForm(tokens.join(" ")));
+    modifier.set_senses(&suffix.senses);
+    modifier.set_modifier(ModifierType::Suffix);
+    modifier.set_id(suffix.id);
+
+    Some(SuffixRule {
+        affix,
+        connect,
+        base_pos,
+        base_key,
+        derived_pos,
+        derived_form,
+        derived_n,
+        derived_key,
+        modifier,
+    })
+}
+
+// enclitics can be stacked, ex: -ne + -que
+const MAX_STACKED_ENCLITICS: usize = 2;
+
+/**
+ * Returns every way the word can be split into a word and its enclitics.
+ * Stacked tackons give one split for each tackon removed, words in the exception list are never split.
+ */
+pub fn split_enclitic(latin_word: &str, trace: &mut Trace) -> Vec<(String, Vec<Modifier>)> {
+    let mut splits: Vec<(String, Vec<Modifier>)> = Vec::new();
     let mut modifiers: Vec<Modifier> = Vec::new();
     let latin_not_packons = get_latin_not_packons();
     let mut split_word = latin_word.to_string();
     let latin_tackons = get_latin_tackons();
     let latin_packons = get_latin_packons();
+    let enclitic_exceptions = get_latin_enclitic_exceptions();
 
-    let tackon = latin_tackons
-        .iter()
-        .find(|tackon| split_word.ends_with(tackon.orth.as_str()));
+    trace.start_stage("split enclitic", latin_word);
+
+    for _ in 0..MAX_STACKED_ENCLITICS {
+        if enclitic_exceptions.contains(&split_word) {
+            trace.reject(&split_word, "listed as an enclitic exception");
+            break;
+        }
+
+        let tackon = latin_tackons.iter().find(|tackon| {
+            split_word.len() > tackon.orth.len() && split_word.ends_with(tackon.orth.as_str())
+        });
 
-    if tackon.is_some() {
-        let tackon = tackon.unwrap();
+        let tackon = match tackon {
+            Some(tackon) => tackon,
+            None => break,
+        };
 
-        // Est exception
-        if latin_word != "est" {
-            let mut modifier = Modifier::new();
-            modifier.set_orth(&tackon.orth);
-            modifier.set_pos(tackon.pos);
-            modifier.set_senses(&tackon.senses);
-            modifier.set_modifier(ModifierType::Tackon);
+        let mut modifier = Modifier::new();
+        modifier.set_orth(&tackon.orth);
+        modifier.set_pos(tackon.pos);
+        modifier.set_senses(&tackon.senses);
+        modifier.set_modifier(ModifierType::Tackon);
+        modifier.set_id(tackon.id);
 
-            split_word.truncate(split_word.len() - tackon.orth.len());
-            modifiers.push(modifier);
+        split_word.truncate(split_word.len() - tackon.orth.len());
+        // modifiers are kept in the order they appear in the word
+        modifiers.insert(0, modifier);
+        if trace.is_enabled() {
+            trace.accept(
+                &format!("-{}", tackon.orth),
+                &format!("tackon removed, leaving '{}'", split_word),
+            );
         }
-    } else {
+
+        splits.push((split_word.clone(), modifiers.clone()));
+    }
+
+    // packons only attach to qu- pronouns, so they are tried even if a tackon was found
+    if (latin_word.starts_with("qu") || splits.is_empty())
+        && !enclitic_exceptions.contains(&latin_word.to_string())
+    {
+        let mut split_word = latin_word.to_string();
+        let mut modifiers: Vec<Modifier> = Vec::new();
+
         if latin_word.starts_with("qu") {
             for packon in latin_packons {
                 if split_word.ends_with(packon.orth.as_str()) {
@@ -106,9 +582,16 @@ pub fn split_enclitic(latin_word: &str) -> (String, Vec<Modifier>) {
                     modifier.set_pos(packon.pos);
                     modifier.set_senses(&packon.senses);
                     modifier.set_modifier(ModifierType::Packon);
+                    modifier.set_id(packon.id);
 
                     split_word.truncate(split_word.len() - packon.orth.len());
                     modifiers.push(modifier);
+                    if trace.is_enabled() {
+                        trace.accept(
+                            &format!("-{}", packon.orth),
+                            &format!("packon removed, leaving '{}'", split_word),
+                        );
+                    }
                 }
             }
         } else {
@@ -119,13 +602,116 @@ pub fn split_enclitic(latin_word: &str) -> (String, Vec<Modifier>) {
                     modifier.set_pos(packon.pos);
                     modifier.set_senses(&packon.senses);
                     modifier.set_modifier(ModifierType::Packon);
+                    modifier.set_id(packon.id);
 
                     split_word.truncate(split_word.len() - packon.orth.len());
                     modifiers.push(modifier);
+                    if trace.is_enabled() {
+                        trace.accept(
+                            &format!("-{}", packon.orth),
+                            &format!("not packon removed, leaving '{}'", split_word),
+                        );
+                    }
                 }
             }
         }
+
+        if !modifiers.is_empty() && !split_word.is_empty() {
+            splits.push((split_word, modifiers));
+        }
+    }
+
+    if splits.is_empty() {
+        trace.reject(latin_word, "no enclitic found");
+    }
+
+    splits
+}
+
+/**
+ * Returns every way the word can be split into a tickon and the qu- pronoun it is attached to.
+ * ex: siquis -> si + quis, nescioquid -> nescio + quid
+ */
+pub fn split_tickon(latin_word: &str, trace: &mut Trace) -> Vec<(String, Modifier)> {
+    let mut splits: Vec<(String, Modifier)> = Vec::new();
+    let enclitic_exceptions = get_latin_enclitic_exceptions();
+
+    trace.start_stage("split tickon", latin_word);
+
+    if enclitic_exceptions.contains(&latin_word.to_string()) {
+        trace.reject(latin_word, "listed as an enclitic exception");
+        return splits;
+    }
+
+    for tickon in get_latin_tickons() {
+        let split_word = match latin_word.strip_prefix(tickon.orth.as_str()) {
+            Some(split_word) => split_word,
+            None => continue,
+        };
+
+        // tickons are only attached to the qui/quis family, which can start with cu- (cuius, cui)
+        if !split_word.starts_with("qu") && !split_word.starts_with("cu") {
+            if trace.is_enabled() {
+                trace.reject(
+                    &format!("{}-", tickon.orth),
+                    &format!("'{}' is not a qu- pronoun", split_word),
+                );
+            }
+            continue;
+        }
+
+        let mut modifier = Modifier::new();
+        modifier.set_orth(&tickon.orth);
+        modifier.set_pos(PartOfSpeech::Packon);
+        modifier.set_senses(&tickon.senses);
+        modifier.set_modifier(ModifierType::Tickon);
+        modifier.set_id(tickon.id);
+
+        if trace.is_enabled() {
+            trace.accept(
+                &format!("{}-", tickon.orth),
+                &format!("tickon removed, leaving '{}'", split_word),
+            );
+        }
+        splits.push((split_word.to_string(), modifier));
+    }
+
+    if splits.is_empty() {
+        trace.reject(latin_word, "no tickon found");
+    }
+
+    splits
+}
+
+/**
+ * Orders analyses so the ones needing the fewest changes to the word come first, then keeps one analysis
+ * per entry and form. Tricks and splits of an entry are dropped when the word is found as is,
+ * ex: est is sum, not ae-trick + a- + sum.
+ */
+pub fn rank_analyses(analyses: Vec<LatinTranslationInfo>) -> Vec<LatinTranslationInfo> {
+    let analyses = sort_entries(analyses, &[SortBy::new(SortKey::Score)], |_| 0);
+
+    let plain_ids: Vec<i32> = analyses
+        .iter()
+        .filter(|analysis| analysis.score() == 0.0)
+        .map(|analysis| analysis.word.id)
+        .collect();
+
+    let mut output: Vec<LatinTranslationInfo> = Vec::new();
+    for analysis in analyses {
+        if analysis.score() != 0.0 && plain_ids.contains(&analysis.word.id) {
+            continue;
+        }
+
+        if output.iter().any(|found| {
+            found.word.id == analysis.word.id
+                && found.word.form.as_str() == analysis.word.form.as_str()
+        }) {
+            continue;
+        }
+
+        output.push(analysis);
     }
 
-    (split_word, modifiers)
+    output
 }
@@ -1,9 +1,16 @@
 pub mod english_to_latin;
 pub mod latin_to_english;
 
-use crate::dictionary_structures::dictionary_values::Form;
+use crate::dictionary_structures::dictionary_keys::{Comparison, PartOfSpeech};
+use crate::dictionary_structures::dictionary_values::{
+    Abbreviation, Form, Inflection, LatinWordInfo,
+};
+use crate::paradigms::all_forms;
+use crate::paradigms::comparison::compared_form;
 use crate::translators::english_to_latin::EnglishTranslationInfo;
+use crate::translators::latin_to_english::trace::Trace;
 use crate::translators::latin_to_english::LatinTranslationInfo;
+use crate::utils::sorting::{sort_entries, SortBy};
 use serde::{Deserialize, Serialize, Serializer};
 use std::mem::take;
 
@@ -38,6 +45,10 @@ pub struct Translation {
     pub word: String,
     #[serde(serialize_with = "serialize_translation")]
     pub definitions: TranslationType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abbreviations: Option<Vec<Abbreviation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace: Option<Trace>,
 }
 
 fn serialize_translation<S>(def: &TranslationType, serializer: S) -> Result<S::Ok, S::Error>
@@ -52,7 +63,20 @@ where
 
 impl Translation {
     pub fn new(word: String, definitions: TranslationType) -> Translation {
-        Translation { word, definitions }
+        Translation {
+            word,
+            definitions,
+            abbreviations: None,
+            trace: None,
+        }
+    }
+
+    pub fn set_abbreviations(&mut self, abbreviations: Vec<Abbreviation>) {
+        self.abbreviations = Some(abbreviations);
+    }
+
+    pub fn set_trace(&mut self, trace: Trace) {
+        self.trace = Some(trace);
     }
 
     pub fn as_json(&self) -> String {
@@ -63,18 +87,15 @@ impl Translation {
      * Makes output more readable.
      * Should only be called after all parsing is done.
      */
-    pub fn post_process(&mut self, language: Language, sort: bool) {
+    pub fn post_process(&mut self, language: Language, sort_by: &[SortBy]) {
         let processed_translation = self;
+        processed_translation.sort(sort_by);
 
         match language {
             Language::Latin => {
-                if sort {
-                    processed_translation.sort();
-                }
-
                 processed_translation.definitions = match &mut processed_translation.definitions {
                     TranslationType::Latin(definitions) => {
-                        let new_definitions: Vec<_> = definitions
+                        let mut new_definitions: Vec<_> = definitions
                             .drain(..)
                             .map(|mut definition| {
                                 definition.word.form.str_form_to_long_form(
@@ -82,6 +103,20 @@ impl Translation {
                                     Structure::LatinWordInfo,
                                 );
 
+                                // melior -> comparative of bonus, needs the stems before they become principle parts
+                                if let Some(inflections) = &definition.inflections {
+                                    let stem_keys: Vec<i8> = inflections
+                                        .iter()
+                                        .filter(|inflection| inflection.pos == definition.word.pos)
+                                        .filter_map(|inflection| inflection.stem_key)
+                                        .collect();
+                                    if let Some(compared) =
+                                        compared_form(&definition.word, &stem_keys)
+                                    {
+                                        definition.set_compared(compared);
+                                    }
+                                }
+
                                 definition.word.generate_principle_parts();
 
                                 definition
@@ -93,11 +128,37 @@ impl Translation {
                                     definition.remove_inflections_with_wrong_pos();
                                     let part_of_speech = definition.word.pos;
 
+                                    inflections.retain(|inflection| {
+                                        inflection.note.as_deref() != Some("greek")
+                                            || fits_declension(inflection, &definition.word)
+                                    });
+
                                     inflections.iter_mut().for_each(|inflection| {
                                         inflection.form.str_form_to_long_form(
                                             part_of_speech,
                                             Structure::Inflection,
                                         );
+
+                                        if let Form::LongForm(form) = &mut inflection.form {
+                                            if inflection.note.as_deref() == Some("greek") {
+                                                form.greek = Some(true);
+                                            }
+
+                                            // the 3rd and 4th stems of an adjective are its comparative and superlative
+                                            if part_of_speech == PartOfSpeech::Adjective {
+                                                match inflection.stem_key {
+                                                    Some(3) => {
+                                                        form.comparison =
+                                                            Some(Comparison::Comparative)
+                                                    }
+                                                    Some(4) => {
+                                                        form.comparison =
+                                                            Some(Comparison::Superlative)
+                                                    }
+                                                    _ => {}
+                                                }
+                                            }
+                                        }
                                     });
 
                                     definition.inflections = Some(inflections);
@@ -105,6 +166,7 @@ impl Translation {
                                 definition
                             })
                             .collect();
+                        number_homographs(&mut new_definitions);
                         TranslationType::Latin(new_definitions)
                     }
                     _ => {
@@ -139,32 +201,48 @@ impl Translation {
         }
     }
 
-    fn sort(&mut self) {
+    // the latin words still have their stems, they are needed for the lemma and forms
+    fn sort(&mut self, sort_by: &[SortBy]) {
         let sorted_translation = self;
 
         match &mut sorted_translation.definitions {
-            &mut TranslationType::Latin(ref mut info) => {
-                info.sort_by(|a, b| {
-                    a.word
-                        .info
-                        .freq
-                        .as_number()
-                        .cmp(&b.word.info.freq.as_number())
-                });
+            TranslationType::Latin(info) => {
+                *info = sort_entries(take(info), sort_by, |info| all_forms(&info.word).len())
+            }
+            TranslationType::English(info) => {
+                *info = sort_entries(take(info), sort_by, |info| {
+                    all_forms(&info.translation).len()
+                })
             }
-            _ => {}
         }
     }
 
     pub fn display(&self, display_type: DisplayType) {
         println!("{}:", self.word);
+        if let (DisplayType::Pretty(_), Some(trace)) = (&display_type, &self.trace) {
+            trace.display();
+        }
+        if let (DisplayType::Pretty(_), Some(abbreviations)) = (&display_type, &self.abbreviations)
+        {
+            for abbreviation in abbreviations {
+                println!("abbreviation: {}", abbreviation.expansion);
+                for sense in &abbreviation.senses {
+                    print!("{} ", sense);
+                }
+                println!("\n");
+            }
+        }
         match display_type {
             DisplayType::Pretty(detailed) => match &self.definitions {
                 TranslationType::Latin(definitions) => {
                     if definitions.is_empty() {
-                        println!("No definitions found");
+                        // an expanded abbreviation is a result by itself
+                        if self.abbreviations.is_none() {
+                            println!("No definitions found");
+                        }
                         return;
                     }
+                    display_homographs(definitions);
                     for definition in definitions {
                         if definition.tricks.is_some() {
                             for trick in definition.tricks.as_ref().unwrap() {
@@ -173,6 +251,9 @@ impl Translation {
                         }
                         if &definition.word.parts.len() > &0 {
                             println!();
+                            if let Some(homograph) = definition.homograph {
+                                print!("{}. ", homograph);
+                            }
                             for part in &definition.word.parts {
                                 print!("{} ", part);
                             }
@@ -187,6 +268,9 @@ impl Translation {
                                 println!("{}", form.as_clean_str());
                             }
                         }
+                        if let Some(compared) = &definition.compared {
+                            println!("{} of {}", compared.comparison, compared.positive);
+                        }
                         if definition.inflections.is_some() {
                             let stem_orth = &definition.stem.orth;
                             for inflection in definition.inflections.as_ref().unwrap() {
@@ -249,6 +333,12 @@ impl Translation {
                             print!("{} ", part);
                         }
                         println!();
+                        if let Some(lemma) = &definition.lemma {
+                            println!("{} of {}", lemma.kind.as_str(), lemma.lemma);
+                        }
+                        if let Some(phrase_match) = &definition.phrase_match {
+                            println!("phrase match: {}", phrase_match.as_str());
+                        }
                         println!("{}", definition.word.pos.as_str());
                         match definition.translation.form {
                             Form::StrForm(ref form) => {
@@ -284,3 +374,117 @@ pub enum TranslationType {
     #[serde(rename = "English")]
     English(Vec<EnglishTranslationInfo>),
 }
+
+/**
+ * Greek endings are only for words of their own declension, ex: epitom.es is epitome and not epitomo
+ * A variant of 0 fits any variant of the declension.
+ */
+fn fits_declension(inflection: &Inflection, word: &LatinWordInfo) -> bool {
+    if inflection.pos != word.pos {
+        return false;
+    }
+
+    match (inflection.n.as_deref(), word.n.as_deref()) {
+        (Some([inflection_1, inflection_2, ..]), Some([word_1, word_2, ..])) => {
+            let (inflection_2, word_2) = (inflection_2.get_n_value_2(), word_2.get_n_value_2());
+            inflection_1.get_n_value_1() == word_1.get_n_value_1()
+                && (inflection_2 == 0 || word_2 == 0 || inflection_2 == word_2)
+        }
+        _ => false,
+    }
+}
+
+fn headword(definition: &LatinTranslationInfo) -> String {
+    definition
+        .word
+        .parts
+        .first()
+        .unwrap_or(&definition.word.orth)
+        .to_lowercase()
+}
+
+// numbers the entries that share a headword, ex: liber 1 (children), liber 2 (book), liber 3 (free)
+// analyses of the same entry get the same number, ex: est -> sum twice
+fn number_homographs(definitions: &mut [LatinTranslationInfo]) {
+    let mut entries: Vec<(String, Vec<i32>)> = Vec::new();
+    for definition in definitions.iter() {
+        let headword = headword(definition);
+        match entries.iter_mut().find(|(other, _)| *other == headword) {
+            Some((_, ids)) if !ids.contains(&definition.word.id) => ids.push(definition.word.id),
+            Some(_) => {}
+            None => entries.push((headword, vec![definition.word.id])),
+        }
+    }
+
+    for definition in definitions.iter_mut() {
+        let headword = headword(definition);
+        let ids = &entries
+            .iter()
+            .find(|(other, _)| *other == headword)
+            .unwrap()
+            .1;
+        if ids.len() > 1 {
+            let number = ids.iter().position(|id| *id == definition.word.id).unwrap();
+            definition.set_homograph(number + 1);
+        }
+    }
+}
+
+// what tells the homographs of each headword apart, one line per entry with the columns lined up
+fn display_homographs(definitions: &[LatinTranslationInfo]) {
+    let mut headwords: Vec<String> = Vec::new();
+    for definition in definitions
+        .iter()
+        .filter(|definition| definition.homograph.is_some())
+    {
+        let headword = headword(definition);
+        if !headwords.contains(&headword) {
+            headwords.push(headword);
+        }
+    }
+
+    for headword_of_group in headwords {
+        let mut rows: Vec<(usize, Vec<String>)> = definitions
+            .iter()
+            .filter(|definition| headword(definition) == headword_of_group)
+            .filter_map(|definition| {
+                let form = match &definition.word.form {
+                    Form::StrForm(form) => form.to_string(),
+                    Form::LongForm(form) => form.as_clean_str(),
+                };
+                let columns = vec![
+                    definition.word.parts.join(" "),
+                    definition.word.pos.as_str().to_string(),
+                    form,
+                    definition.word.info.age.as_str().to_string(),
+                    definition.word.info.freq.as_str().to_string(),
+                    definition.word.senses.first().cloned().unwrap_or_default(),
+                ];
+                definition.homograph.map(|homograph| (homograph, columns))
+            })
+            .collect();
+
+        // one row per entry, an entry can be found more than once
+        rows.sort_by_key(|(homograph, _)| *homograph);
+        rows.dedup_by_key(|(homograph, _)| *homograph);
+
+        let widths: Vec<usize> = (0..rows[0].1.len())
+            .map(|column| {
+                rows.iter()
+                    .map(|(_, columns)| columns[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        println!("\n{} has {} entries:", headword_of_group, rows.len());
+        for (homograph, columns) in rows {
+            let padded: Vec<String> = columns
+                .iter()
+                .zip(&widths)
+                .map(|(column, width)| format!("{:width$}", column, width = width))
+                .collect();
+            println!("  {}. {}", homograph, padded.join(" | ").trim_end());
+        }
+    }
+}
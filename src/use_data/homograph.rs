@@ -0,0 +1,80 @@
+use crate::dictionary_structures::dictionary_values::LatinWordInfo;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/**
+ * Dictionary entries with the same headword, ex: liber (children), liber (book), liber (free)
+ */
+#[derive(Debug, Serialize)]
+pub struct Homograph {
+    pub headword: String,
+    pub words: Vec<LatinWordInfo>,
+}
+
+/**
+ * The homographs made of the same parts of speech, ex: "adjective, noun" for liber
+ */
+#[derive(Debug, Serialize)]
+pub struct HomographGroup {
+    pub pos: String,
+    pub homographs: Vec<Homograph>,
+}
+
+/**
+ * Finds the headwords shared by more than one entry, in the order of the dictionary.
+ * The principle parts of the words must already be generated, so the orth is the headword.
+ */
+pub fn find_homographs(dictionary: Vec<LatinWordInfo>) -> Vec<Homograph> {
+    let mut homographs: Vec<Homograph> = Vec::new();
+    let mut index_of: HashMap<String, usize> = HashMap::new();
+
+    for word in dictionary {
+        let headword = word.orth.to_lowercase();
+        match index_of.get(&headword) {
+            Some(index) => homographs[*index].words.push(word),
+            None => {
+                index_of.insert(headword.clone(), homographs.len());
+                homographs.push(Homograph {
+                    headword,
+                    words: vec![word],
+                });
+            }
+        }
+    }
+
+    homographs.retain(|homograph| homograph.words.len() > 1);
+    homographs
+}
+
+/**
+ * Groups homographs by the parts of speech of their entries, single part of speech groups first
+ */
+pub fn group_homographs(homographs: Vec<Homograph>) -> Vec<HomographGroup> {
+    let mut groups: Vec<HomographGroup> = Vec::new();
+
+    for homograph in homographs {
+        let mut pos_list: Vec<&str> = homograph
+            .words
+            .iter()
+            .map(|word| word.pos.as_str())
+            .collect();
+        pos_list.sort();
+        pos_list.dedup();
+        let pos = pos_list.join(", ");
+
+        match groups.iter_mut().find(|group| group.pos == pos) {
+            Some(group) => group.homographs.push(homograph),
+            None => groups.push(HomographGroup {
+                pos,
+                homographs: vec![homograph],
+            }),
+        }
+    }
+
+    groups.sort_by(|a, b| {
+        let a_count = a.pos.split(", ").count();
+        let b_count = b.pos.split(", ").count();
+        a_count.cmp(&b_count).then(a.pos.cmp(&b.pos))
+    });
+    groups
+}
@@ -0,0 +1,175 @@
+use crate::dictionary_structures::dictionary_keys::PartOfSpeech;
+use crate::dictionary_structures::dictionary_values::{
+    Attachment, EnglishWordInfo, Inflection, LatinWordInfo, Modifier, NValue, Stem,
+};
+use crate::paradigms::inflections_for;
+use crate::use_data::WordType;
+use crate::utils::data::{
+    get_english_dictionary, get_latin_dictionary, get_latin_inflections, get_latin_not_packons,
+    get_latin_packons, get_latin_prefixes, get_latin_stems, get_latin_suffixes, get_latin_tackons,
+    get_latin_tickons, get_unique_latin_words,
+};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum Entry {
+    Latin(LatinWordInfo),
+    English(EnglishWordInfo),
+    Inflection(Inflection),
+    Stem(Stem),
+    Modifier(Modifier),
+    Attachment(Attachment),
+}
+
+/**
+ * An entry of a table with the entries of other tables that refer to it or that it refers to.
+ * ex: a stem with its dictionary entry, the inflections that go on it and the english words of its entry
+ */
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub table: String,
+    pub id: i32,
+    pub entry: Entry,
+    // the dictionary entry of a stem or english word
+    pub word: Option<LatinWordInfo>,
+    pub stems: Vec<Stem>,
+    pub inflections: Vec<Inflection>,
+    pub english: Vec<EnglishWordInfo>,
+}
+
+impl Record {
+    fn new(word_type: &WordType, id: i32, entry: Entry) -> Record {
+        Record {
+            table: word_type.as_str().to_string(),
+            id,
+            entry,
+            word: None,
+            stems: Vec::new(),
+            inflections: Vec::new(),
+            english: Vec::new(),
+        }
+    }
+}
+
+/**
+ * Finds an entry of a table by its id, ex: latin 2871 (amo), stems 28711, unique_latin 100001
+ */
+pub fn find_by_id(word_type: &WordType, id: i32) -> Result<Record, String> {
+    let not_found = || format!("No entry with id {} in {}", id, word_type.as_str());
+
+    let record = match word_type {
+        WordType::Latin => {
+            let word = find(get_latin_dictionary(), id, |word| word.id).ok_or_else(not_found)?;
+            let mut record = Record::new(word_type, id, Entry::Latin(word.clone()));
+            record.stems = stems_of(id);
+            record.inflections = inflections_of(word.pos, &word.n);
+            record.english = english_of(id);
+            record
+        }
+        WordType::Homographs => {
+            return Err("Homographs have no ids, use the ids of their latin entries".to_string())
+        }
+        WordType::UniqueLatin => {
+            let word = find(get_unique_latin_words(), id, |word| word.id).ok_or_else(not_found)?;
+            // the inflections the unique form stands for, ex: agantur is PRES PASSIVE SUB 3 P
+            let unique_form = normalize_form(&word.form.as_str());
+            let inflections = inflections_of(word.pos, &word.n)
+                .into_iter()
+                .filter(|inflection| {
+                    let form = normalize_form(&inflection.form.as_str());
+                    !form.is_empty() && unique_form.contains(&form)
+                })
+                .collect();
+            let mut record = Record::new(word_type, id, Entry::Latin(word));
+            record.inflections = inflections;
+            record
+        }
+        WordType::English => {
+            let english_word =
+                find(get_english_dictionary(), id, |word| word.id).ok_or_else(not_found)?;
+            let wid = english_word.wid;
+            let mut record = Record::new(word_type, id, Entry::English(english_word));
+            record.word = find(get_latin_dictionary(), wid, |word| word.id);
+            record.stems = stems_of(wid);
+            record
+        }
+        WordType::Stems => {
+            let stem = find(get_latin_stems(), id, |stem| stem.id).ok_or_else(not_found)?;
+            let wid = stem.wid;
+            let inflections = inflections_of(stem.pos, &stem.n);
+            let mut record = Record::new(word_type, id, Entry::Stem(stem));
+            record.word = find(get_latin_dictionary(), wid, |word| word.id);
+            record.inflections = inflections;
+            record.english = english_of(wid);
+            record
+        }
+        WordType::Inflections => {
+            let inflection = find(get_latin_inflections(), id, |inflection| inflection.id)
+                .ok_or_else(not_found)?;
+            Record::new(word_type, id, Entry::Inflection(inflection))
+        }
+        WordType::Prefixes | WordType::Suffixes => {
+            let modifiers = match word_type {
+                WordType::Prefixes => get_latin_prefixes(),
+                _ => get_latin_suffixes(),
+            };
+            let modifier = find(modifiers, id, |modifier| modifier.id).ok_or_else(not_found)?;
+            Record::new(word_type, id, Entry::Modifier(modifier))
+        }
+        WordType::Packons | WordType::NotPackons | WordType::Tackons | WordType::Tickons => {
+            let attachments = match word_type {
+                WordType::Packons => get_latin_packons(),
+                WordType::NotPackons => get_latin_not_packons(),
+                WordType::Tackons => get_latin_tackons(),
+                _ => get_latin_tickons(),
+            };
+            let attachment =
+                find(attachments, id, |attachment| attachment.id).ok_or_else(not_found)?;
+            Record::new(word_type, id, Entry::Attachment(attachment))
+        }
+    };
+
+    Ok(record)
+}
+
+fn find<T>(entries: Vec<T>, id: i32, id_of: fn(&T) -> i32) -> Option<T> {
+    entries.into_iter().find(|entry| id_of(entry) == id)
+}
+
+fn stems_of(wid: i32) -> Vec<Stem> {
+    get_latin_stems()
+        .into_iter()
+        .filter(|stem| stem.wid == wid)
+        .collect()
+}
+
+fn english_of(wid: i32) -> Vec<EnglishWordInfo> {
+    get_english_dictionary()
+        .into_iter()
+        .filter(|english_word| english_word.wid == wid)
+        .collect()
+}
+
+// the endings of the declension or conjugation, verbs also take the endings of their participles
+fn inflections_of(pos: PartOfSpeech, n: &Option<Vec<NValue>>) -> Vec<Inflection> {
+    let (num_type_1, num_type_2) = match n {
+        Some(n) if n.len() >= 2 => (n[0].get_n_value_1(), n[1].get_n_value_2()),
+        _ => return Vec::new(),
+    };
+
+    let mut inflections = inflections_for(pos, num_type_1, &[num_type_2]);
+    if pos == PartOfSpeech::Verb {
+        inflections.extend(inflections_for(
+            PartOfSpeech::Participle,
+            num_type_1,
+            &[num_type_2],
+        ));
+    }
+    inflections
+}
+
+// the forms in the data are padded with spaces, ex: "PRES  ACTIVE  IND  3 S"
+fn normalize_form(form: &str) -> String {
+    form.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
@@ -1,17 +1,25 @@
+use self::homograph::HomographGroup;
 use self::parsers::attachment_parser::parse_attachments;
 use self::parsers::english_dictionary_parser::parse_english_dictionary;
-use self::parsers::latin_dictionary_parser::parse_latin_dictionary;
+use self::parsers::latin_dictionary_parser::{
+    parse_homographs, parse_latin_dictionary, parse_latin_patterns,
+};
 use self::parsers::latin_inflection_parser::parse_latin_inflections;
 use self::parsers::modifiers_parser::parse_modifiers;
 use self::parsers::stem_parser::parse_latin_stems;
+use self::pattern::{PatternMatch, WordPatterns};
+use self::sampler::Sampler;
+use self::where_clause::{Filterable, WhereClause};
 use crate::dictionary_structures::dictionary_keys::PartOfSpeech;
 use crate::dictionary_structures::dictionary_values::{
     Attachment, EnglishWordInfo, Inflection, LatinWordInfo, Modifier, Stem,
 };
 use crate::utils::data::{
-    get_latin_dictionary, get_latin_not_packons, get_latin_packons, get_latin_prefixes,
-    get_latin_suffixes, get_latin_tackons, get_latin_tickons, get_unique_latin_words,
+    get_english_dictionary, get_latin_dictionary, get_latin_inflections, get_latin_not_packons,
+    get_latin_packons, get_latin_prefixes, get_latin_stems, get_latin_suffixes, get_latin_tackons,
+    get_latin_tickons, get_unique_latin_words,
 };
+use crate::utils::sorting::{sort_entries, SortBy};
 use serde::Serialize;
 use serde_json;
 
@@ -24,11 +32,17 @@ mod parsers {
     pub mod stem_parser;
 }
 
+pub mod homograph;
+pub mod lookup;
+pub mod pattern;
+pub mod sampler;
 mod utils;
+pub mod where_clause;
 
 #[derive(Debug)]
 pub enum WordType {
     English,
+    Homographs,
     Latin,
     Inflections,
     NotPackons,
@@ -55,15 +69,34 @@ impl WordType {
             "tackons" | "tackon" => Ok(WordType::Tackons),
             "tickons" | "tickon" => Ok(WordType::Tickons),
             "unique_latin" => Ok(WordType::UniqueLatin), // done
+            "homographs" | "homograph" => Ok(WordType::Homographs),
             _ => Err(format!("Invalid word type: {}", s)),
         }
     }
 
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WordType::English => "english",
+            WordType::Homographs => "homographs",
+            WordType::Latin => "latin",
+            WordType::Inflections => "inflections",
+            WordType::NotPackons => "not_packons",
+            WordType::Packons => "packons",
+            WordType::Prefixes => "prefixes",
+            WordType::Stems => "stems",
+            WordType::Suffixes => "suffixes",
+            WordType::Tackons => "tackons",
+            WordType::Tickons => "tickons",
+            WordType::UniqueLatin => "unique_latin",
+        }
+    }
+
     pub fn is_valid_word_type(s: &str) -> bool {
         match s {
-            "english" | "latin" | "inflections" | "inflection" | "not_packons" | "not_packon"
-            | "packon" | "packons" | "prefixes" | "prefix" | "stems" | "stem" | "suffixes"
-            | "suffix" | "tackons" | "tackon" | "tickons" | "tickon" | "unique_latin" => true,
+            "english" | "homographs" | "homograph" | "latin" | "inflections" | "inflection"
+            | "not_packons" | "not_packon" | "packon" | "packons" | "prefixes" | "prefix"
+            | "stems" | "stem" | "suffixes" | "suffix" | "tackons" | "tackon" | "tickons"
+            | "tickon" | "unique_latin" => true,
             _ => false,
         }
     }
@@ -78,75 +111,226 @@ pub enum OutputList {
     Attachment(Vec<Attachment>),
     Modifiers(Vec<Modifier>),
     Stems(Vec<Stem>),
+    Matches(Vec<PatternMatch>),
+    Homographs(Vec<HomographGroup>),
+}
+
+impl OutputList {
+    pub fn count(&self) -> usize {
+        match self {
+            OutputList::Latin(list) => list.len(),
+            OutputList::English(list) => list.len(),
+            OutputList::Inflections(list) => list.len(),
+            OutputList::Attachment(list) => list.len(),
+            OutputList::Modifiers(list) => list.len(),
+            OutputList::Stems(list) => list.len(),
+            OutputList::Matches(list) => list.len(),
+            OutputList::Homographs(groups) => {
+                groups.iter().map(|group| group.homographs.len()).sum()
+            }
+        }
+    }
 }
 
-pub fn get_list(
-    word_type: WordType,
+/**
+ * Which entries of a table go in a list and in what order, ex: the 10 most frequent verbs of 5 letters
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
     pos_list: Option<Vec<PartOfSpeech>>,
     max: Option<i32>,
     min: Option<i32>,
     exact: Option<i32>,
-    amount: Option<i32>,
-    random: bool,
-    display: bool,
-    to: Option<String>,
-) {
-    let list: OutputList = match word_type {
+    patterns: WordPatterns,
+    where_clause: WhereClause,
+    sort_by: Vec<SortBy>,
+    sampler: Sampler,
+}
+
+impl ListOptions {
+    pub fn new() -> ListOptions {
+        ListOptions::default()
+    }
+
+    pub fn set_pos_list(mut self, pos_list: Option<Vec<PartOfSpeech>>) -> ListOptions {
+        self.pos_list = pos_list;
+        self
+    }
+
+    pub fn set_lengths(
+        mut self,
+        max: Option<i32>,
+        min: Option<i32>,
+        exact: Option<i32>,
+    ) -> ListOptions {
+        self.max = max;
+        self.min = min;
+        self.exact = exact;
+        self
+    }
+
+    pub fn set_patterns(mut self, patterns: WordPatterns) -> ListOptions {
+        self.patterns = patterns;
+        self
+    }
+
+    pub fn set_where_clause(mut self, where_clause: WhereClause) -> ListOptions {
+        self.where_clause = where_clause;
+        self
+    }
+
+    pub fn set_sort_by(mut self, sort_by: Vec<SortBy>) -> ListOptions {
+        self.sort_by = sort_by;
+        self
+    }
+
+    pub fn set_sampler(mut self, sampler: Sampler) -> ListOptions {
+        self.sampler = sampler;
+        self
+    }
+}
+
+pub fn get_list(word_type: WordType, options: ListOptions, display: bool, to: Option<String>) {
+    let ListOptions {
+        pos_list,
+        max,
+        min,
+        exact,
+        patterns,
+        where_clause,
+        sort_by,
+        sampler,
+    } = options;
+
+    if !patterns.is_empty() && !matches!(word_type, WordType::Latin | WordType::UniqueLatin) {
+        println!("Patterns can only be used with latin and unique_latin words");
+        return;
+    }
+
+    if !sort_by.is_empty()
+        && !matches!(
+            word_type,
+            WordType::English | WordType::Latin | WordType::UniqueLatin
+        )
+    {
+        println!("Sorting can only be used with english, latin and unique_latin words");
+        return;
+    }
+
+    if let Some(sort) = sort_by.iter().find(|sort| !sort.key.sorts_lists()) {
+        println!(
+            "Sorting by {} can only be used with translations",
+            sort.key.as_str()
+        );
+        return;
+    }
+
+    let (total, list): (usize, OutputList) = match word_type {
         WordType::English => {
-            let list = parse_english_dictionary(pos_list, max, min, exact, amount, random);
-            OutputList::English(list)
+            let dictionary = sort_entries(
+                fitting(get_english_dictionary(), &where_clause),
+                &sort_by,
+                uncounted,
+            );
+            let sample = parse_english_dictionary(dictionary, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::English(sample.entries))
         }
-        WordType::Latin => {
+        WordType::Homographs => {
             let dictionary = get_latin_dictionary();
-            let list =
-                parse_latin_dictionary(dictionary, pos_list, max, min, exact, amount, random);
-            OutputList::Latin(list)
+            let sample = parse_homographs(
+                dictionary,
+                &where_clause,
+                pos_list,
+                max,
+                min,
+                exact,
+                &sampler,
+            );
+            (sample.total, OutputList::Homographs(sample.entries))
+        }
+        WordType::Latin if !patterns.is_empty() => {
+            let dictionary = sort_entries(
+                fitting(get_latin_dictionary(), &where_clause),
+                &sort_by,
+                uncounted,
+            );
+            let sample =
+                parse_latin_patterns(dictionary, &patterns, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::Matches(sample.entries))
+        }
+        WordType::Latin => {
+            let dictionary = sort_entries(
+                fitting(get_latin_dictionary(), &where_clause),
+                &sort_by,
+                uncounted,
+            );
+            let sample = parse_latin_dictionary(dictionary, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::Latin(sample.entries))
         }
         WordType::Inflections => {
-            let list = parse_latin_inflections(pos_list, max, min, exact, amount, random);
-            OutputList::Inflections(list)
+            let inflections = fitting(get_latin_inflections(), &where_clause);
+            let sample = parse_latin_inflections(inflections, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::Inflections(sample.entries))
         }
         WordType::NotPackons => {
-            let attachments = get_latin_not_packons();
-            let list = parse_attachments(attachments, None, max, min, exact, amount, random);
-            OutputList::Attachment(list)
+            let attachments = fitting(get_latin_not_packons(), &where_clause);
+            let sample = parse_attachments(attachments, None, max, min, exact, &sampler);
+            (sample.total, OutputList::Attachment(sample.entries))
         }
         WordType::Packons => {
-            let attachments = get_latin_packons();
-            let list = parse_attachments(attachments, None, max, min, exact, amount, random);
-            OutputList::Attachment(list)
+            let attachments = fitting(get_latin_packons(), &where_clause);
+            let sample = parse_attachments(attachments, None, max, min, exact, &sampler);
+            (sample.total, OutputList::Attachment(sample.entries))
         }
         WordType::Prefixes => {
-            let modifiers = get_latin_prefixes();
-            let list = parse_modifiers(modifiers, pos_list, max, min, exact, amount, random);
-            OutputList::Modifiers(list)
+            let modifiers = fitting(get_latin_prefixes(), &where_clause);
+            let sample = parse_modifiers(modifiers, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::Modifiers(sample.entries))
         }
         WordType::Stems => {
-            let list = parse_latin_stems(pos_list, max, min, exact, amount, random);
-            OutputList::Stems(list)
+            let stems = fitting(get_latin_stems(), &where_clause);
+            let sample = parse_latin_stems(stems, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::Stems(sample.entries))
         }
         WordType::Suffixes => {
-            let modifiers = get_latin_suffixes();
-            let list = parse_modifiers(modifiers, pos_list, max, min, exact, amount, random);
-            OutputList::Modifiers(list)
+            let modifiers = fitting(get_latin_suffixes(), &where_clause);
+            let sample = parse_modifiers(modifiers, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::Modifiers(sample.entries))
         }
         WordType::Tackons => {
-            let attachments = get_latin_tackons();
-            let list = parse_attachments(attachments, None, max, min, exact, amount, random);
-            OutputList::Attachment(list)
+            let attachments = fitting(get_latin_tackons(), &where_clause);
+            let sample = parse_attachments(attachments, None, max, min, exact, &sampler);
+            (sample.total, OutputList::Attachment(sample.entries))
         }
         WordType::Tickons => {
-            let attachments = get_latin_tickons();
-            let list = parse_attachments(attachments, None, max, min, exact, amount, random);
-            OutputList::Attachment(list)
+            let attachments = fitting(get_latin_tickons(), &where_clause);
+            let sample = parse_attachments(attachments, None, max, min, exact, &sampler);
+            (sample.total, OutputList::Attachment(sample.entries))
+        }
+        WordType::UniqueLatin if !patterns.is_empty() => {
+            let dictionary = sort_entries(
+                fitting(get_unique_latin_words(), &where_clause),
+                &sort_by,
+                uncounted,
+            );
+            let sample =
+                parse_latin_patterns(dictionary, &patterns, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::Matches(sample.entries))
         }
         WordType::UniqueLatin => {
-            let dictionary = get_unique_latin_words();
-            let list =
-                parse_latin_dictionary(dictionary, pos_list, max, min, exact, amount, random);
-            OutputList::Latin(list)
+            let dictionary = sort_entries(
+                fitting(get_unique_latin_words(), &where_clause),
+                &sort_by,
+                uncounted,
+            );
+            let sample = parse_latin_dictionary(dictionary, pos_list, max, min, exact, &sampler);
+            (sample.total, OutputList::Latin(sample.entries))
         }
     };
+    if let Some(summary) = sampler.summary(total, list.count()) {
+        println!("{}", summary);
+    }
+
     if display {
         println!("{}", serde_json::to_string_pretty(&list).unwrap());
     }
@@ -178,3 +362,18 @@ pub fn get_list(
         println!("File created successfully at {}", file_path);
     }
 }
+
+// lists reject the forms sort key, so their forms are never counted
+fn uncounted<T>(_: &T) -> usize {
+    0
+}
+
+fn fitting<T: Filterable>(entries: Vec<T>, where_clause: &WhereClause) -> Vec<T> {
+    if where_clause.is_empty() {
+        return entries;
+    }
+    entries
+        .into_iter()
+        .filter(|entry| where_clause.fits(entry))
+        .collect()
+}
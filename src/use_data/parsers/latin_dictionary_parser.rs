@@ -1,7 +1,10 @@
 use crate::dictionary_structures::dictionary_keys::PartOfSpeech;
 use crate::dictionary_structures::dictionary_values::LatinWordInfo;
+use crate::use_data::homograph::{find_homographs, group_homographs, HomographGroup};
+use crate::use_data::pattern::{PatternMatch, WordPatterns};
+use crate::use_data::sampler::{Sample, Sampler};
 use crate::use_data::utils::word_fits_filters;
-use rand::Rng;
+use crate::use_data::where_clause::WhereClause;
 
 // need to generate principal parts before checking if the word fits the filter, to account for length filters
 pub fn parse_latin_dictionary(
@@ -10,52 +13,66 @@ pub fn parse_latin_dictionary(
     max: Option<i32>,
     min: Option<i32>,
     exact: Option<i32>,
-    amount: Option<i32>,
-    random: bool,
-) -> Vec<LatinWordInfo> {
-    let mut latin_word_info_list: Vec<LatinWordInfo> = Vec::new();
+    sampler: &Sampler,
+) -> Sample<LatinWordInfo> {
+    let matches = dictionary
+        .into_iter()
+        .map(|mut word| {
+            word.generate_principle_parts();
+            word
+        })
+        .filter(|word| word_fits_filters(&word.orth, &word.pos, &pos_list, &max, &min, &exact));
+
+    sampler.sample(matches)
+}
+
+// the patterns are matched against the stems, so the principal parts are generated after
+pub fn parse_latin_patterns(
+    dictionary: Vec<LatinWordInfo>,
+    patterns: &WordPatterns,
+    pos_list: Option<Vec<PartOfSpeech>>,
+    max: Option<i32>,
+    min: Option<i32>,
+    exact: Option<i32>,
+    sampler: &Sampler,
+) -> Sample<PatternMatch> {
+    let matches = dictionary.into_iter().filter_map(|mut word| {
+        let matched = patterns.matching_form(&word, &pos_list, &max, &min, &exact)?;
+        word.generate_principle_parts();
+        Some(PatternMatch { matched, word })
+    });
 
-    if let Some(amount) = amount {
-        if random {
-            let mut rng = rand::thread_rng();
-            while latin_word_info_list.len() as i32 != amount {
-                let random_index = rng.gen_range(0..dictionary.len());
-                let mut word_at_index = dictionary[random_index].clone();
-                word_at_index.generate_principle_parts();
-                if !word_fits_filters(
-                    &word_at_index.orth,
-                    &word_at_index.pos,
-                    &pos_list,
-                    &max,
-                    &min,
-                    &exact,
-                ) {
-                    continue;
-                }
-                latin_word_info_list.push(word_at_index);
-            }
-        } else {
-            for mut word in dictionary {
-                word.generate_principle_parts();
-                if !word_fits_filters(&word.orth, &word.pos, &pos_list, &max, &min, &exact) {
-                    continue;
-                }
-                latin_word_info_list.push(word);
-                if latin_word_info_list.len() as i32 == amount {
-                    break;
-                }
-            }
-        }
-    } else {
-        for mut word in dictionary {
+    sampler.sample(matches)
+}
+
+// the filters keep the homographs with an entry that fits them, so a homograph is never split
+pub fn parse_homographs(
+    dictionary: Vec<LatinWordInfo>,
+    where_clause: &WhereClause,
+    pos_list: Option<Vec<PartOfSpeech>>,
+    max: Option<i32>,
+    min: Option<i32>,
+    exact: Option<i32>,
+    sampler: &Sampler,
+) -> Sample<HomographGroup> {
+    let dictionary: Vec<LatinWordInfo> = dictionary
+        .into_iter()
+        .map(|mut word| {
             word.generate_principle_parts();
-            if !word_fits_filters(&word.orth, &word.pos, &pos_list, &max, &min, &exact) {
-                continue;
-            }
+            word
+        })
+        .collect();
 
-            latin_word_info_list.push(word);
-        }
-    }
+    let matches = find_homographs(dictionary).into_iter().filter(|homograph| {
+        homograph.words.iter().any(|word| {
+            where_clause.fits(word)
+                && word_fits_filters(&word.orth, &word.pos, &pos_list, &max, &min, &exact)
+        })
+    });
 
-    latin_word_info_list
+    let sample = sampler.sample(matches);
+    Sample {
+        total: sample.total,
+        entries: group_homographs(sample.entries),
+    }
 }
@@ -0,0 +1,157 @@
+use crate::dictionary_structures::dictionary_keys::PartOfSpeech;
+use crate::dictionary_structures::dictionary_values::LatinWordInfo;
+use crate::paradigms::all_forms;
+use crate::use_data::utils::word_fits_filters;
+use regex::Regex;
+use serde::Serialize;
+
+/**
+ * A way of matching the spelling of a word
+ */
+#[derive(Debug, Clone)]
+pub enum WordPattern {
+    // a?o matches amo, *tio matches oratio
+    Glob(Regex),
+    Regex(Regex),
+    // the sorted letters of the word, a ? stands for any letter, ex: roma -> amor, mora
+    Anagram(Vec<char>),
+}
+
+impl WordPattern {
+    pub fn glob(pattern: &str) -> Result<WordPattern, String> {
+        let mut regex = String::from("^");
+        for c in pattern.to_lowercase().chars() {
+            match c {
+                '?' => regex.push('.'),
+                '*' => regex.push_str(".*"),
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        regex.push('$');
+
+        Regex::new(&regex)
+            .map(WordPattern::Glob)
+            .map_err(|error| format!("Invalid pattern {}: {}", pattern, error))
+    }
+
+    pub fn regex(pattern: &str) -> Result<WordPattern, String> {
+        Regex::new(pattern)
+            .map(WordPattern::Regex)
+            .map_err(|error| format!("Invalid regular expression {}: {}", pattern, error))
+    }
+
+    pub fn anagram(letters: &str) -> WordPattern {
+        let mut letters: Vec<char> = letters
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphabetic() || *c == '?')
+            .collect();
+        letters.sort();
+        WordPattern::Anagram(letters)
+    }
+
+    pub fn matches(&self, word: &str) -> bool {
+        match self {
+            WordPattern::Glob(regex) | WordPattern::Regex(regex) => regex.is_match(word),
+            WordPattern::Anagram(letters) => {
+                if word.chars().count() != letters.len() {
+                    return false;
+                }
+
+                let mut remaining: Vec<char> = letters.clone();
+                for c in word.chars() {
+                    match remaining.iter().position(|letter| *letter == c) {
+                        Some(index) => {
+                            remaining.remove(index);
+                        }
+                        None => match remaining.iter().position(|letter| *letter == '?') {
+                            Some(index) => {
+                                remaining.remove(index);
+                            }
+                            None => return false,
+                        },
+                    }
+                }
+                true
+            }
+        }
+    }
+}
+
+/**
+ * Patterns that one spelling of a word must all match, the lemma and principle parts and optionally every form
+ */
+#[derive(Debug, Clone, Default)]
+pub struct WordPatterns {
+    pub patterns: Vec<WordPattern>,
+    pub forms: bool,
+}
+
+impl WordPatterns {
+    pub fn new() -> WordPatterns {
+        WordPatterns::default()
+    }
+
+    pub fn add_pattern(mut self, pattern: WordPattern) -> WordPatterns {
+        self.patterns.push(pattern);
+        self
+    }
+
+    pub fn set_forms(mut self, forms: bool) -> WordPatterns {
+        self.forms = forms;
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /**
+     * The first spelling of a word that matches every pattern and the length filters.
+     * Needs the stems of the word, so it must be called before the principle parts are generated.
+     */
+    pub fn matching_form(
+        &self,
+        word: &LatinWordInfo,
+        pos_list: &Option<Vec<PartOfSpeech>>,
+        max: &Option<i32>,
+        min: &Option<i32>,
+        exact: &Option<i32>,
+    ) -> Option<String> {
+        // the pos does not depend on the spelling
+        if !word_fits_filters("", &word.pos, pos_list, &None, &None, &None) {
+            return None;
+        }
+
+        let mut spellings: Vec<String> = match word.principle_parts() {
+            // profectus sum -> profectus
+            Ok(parts) => parts
+                .iter()
+                .filter_map(|part| part.split_whitespace().next())
+                .filter(|part| part.chars().all(|c| c.is_alphabetic()))
+                .map(|part| part.to_lowercase())
+                .collect(),
+            Err(_) => vec![word.orth.to_lowercase()],
+        };
+        if self.forms {
+            spellings.extend(all_forms(word));
+        }
+
+        spellings.into_iter().find(|spelling| {
+            word_fits_filters(spelling, &word.pos, pos_list, max, min, exact)
+                && self
+                    .patterns
+                    .iter()
+                    .all(|pattern| pattern.matches(spelling))
+        })
+    }
+}
+
+/**
+ * A word with the spelling of it that matched the patterns, ex: amabo (amo)
+ */
+#[derive(Debug, Serialize)]
+pub struct PatternMatch {
+    pub matched: String,
+    pub word: LatinWordInfo,
+}
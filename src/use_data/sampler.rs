@@ -0,0 +1,96 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+/**
+ * Picks the entries of a list to output, a page of the matches or of a random sample of them
+ */
+#[derive(Debug, Clone, Default)]
+pub struct Sampler {
+    amount: Option<usize>,
+    offset: usize,
+    random: bool,
+    seed: Option<u64>,
+    count: bool,
+}
+
+/**
+ * The entries picked from the matches, with the number of matches there were
+ */
+#[derive(Debug)]
+pub struct Sample<T> {
+    pub total: usize,
+    pub entries: Vec<T>,
+}
+
+impl Sampler {
+    pub fn new() -> Sampler {
+        Sampler::default()
+    }
+
+    pub fn set_amount(mut self, amount: Option<usize>) -> Sampler {
+        self.amount = amount;
+        self
+    }
+
+    pub fn set_offset(mut self, offset: usize) -> Sampler {
+        self.offset = offset;
+        self
+    }
+
+    pub fn set_random(mut self, random: bool) -> Sampler {
+        self.random = random;
+        self
+    }
+
+    pub fn set_seed(mut self, seed: Option<u64>) -> Sampler {
+        self.seed = seed;
+        self
+    }
+
+    pub fn set_count(mut self, count: bool) -> Sampler {
+        self.count = count;
+        self
+    }
+
+    /**
+     * Every match is kept until the page is taken, so random entries are never repeated.
+     * The same seed shuffles the matches the same way, so the pages of a random sample do not overlap.
+     */
+    pub fn sample<T>(&self, matches: impl IntoIterator<Item = T>) -> Sample<T> {
+        let mut entries: Vec<T> = matches.into_iter().collect();
+        let total = entries.len();
+
+        if self.random {
+            match self.seed {
+                Some(seed) => entries.shuffle(&mut StdRng::seed_from_u64(seed)),
+                None => entries.shuffle(&mut rand::thread_rng()),
+            }
+        }
+
+        let entries = entries
+            .into_iter()
+            .skip(self.offset)
+            .take(self.amount.unwrap_or(usize::MAX))
+            .collect();
+
+        Sample { total, entries }
+    }
+
+    // ex: 11-20 of 154 entries, only when the count was asked for
+    pub fn summary(&self, total: usize, shown: usize) -> Option<String> {
+        if !self.count {
+            return None;
+        }
+
+        if shown == 0 {
+            return Some(format!("0 of {} entries", total));
+        }
+        Some(format!(
+            "{}-{} of {} entries",
+            self.offset + 1,
+            self.offset + shown,
+            total
+        ))
+    }
+}
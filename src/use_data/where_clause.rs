@@ -0,0 +1,393 @@
+use crate::dictionary_structures::dictionary_keys::{
+    Age, Area, Frequency, Gender, Geography, Noun, PartOfSpeech, Source, Verb,
+};
+use crate::dictionary_structures::dictionary_values::{
+    Attachment, EnglishWordInfo, Form, Inflection, LatinWordInfo, LongForm, Modifier, NValue, Stem,
+};
+use crate::translators::Structure;
+
+/**
+ * What a condition can be about, the grammatical fields come from the form of the entry
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Field {
+    Age,
+    Area,
+    Geography,
+    Frequency,
+    Source,
+    PartOfSpeech,
+    Declension,
+    Conjugation,
+    Gender,
+    NounKind,
+    VerbKind,
+}
+
+impl Field {
+    fn from_name(name: &str) -> Result<Field, String> {
+        match name {
+            "age" => Ok(Field::Age),
+            "area" => Ok(Field::Area),
+            "geo" | "geography" => Ok(Field::Geography),
+            "freq" | "frequency" => Ok(Field::Frequency),
+            "source" => Ok(Field::Source),
+            "pos" => Ok(Field::PartOfSpeech),
+            "decl" | "declension" => Ok(Field::Declension),
+            "conj" | "conjugation" => Ok(Field::Conjugation),
+            "gender" => Ok(Field::Gender),
+            "noun" | "noun_kind" => Ok(Field::NounKind),
+            "verb" | "verb_kind" => Ok(Field::VerbKind),
+            _ => Err(format!(
+                "Invalid field: {}, use age, area, geo, freq, source, pos, decl, conj, gender, noun or verb",
+                name
+            )),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Field::Age => "age",
+            Field::Area => "area",
+            Field::Geography => "geo",
+            Field::Frequency => "freq",
+            Field::Source => "source",
+            Field::PartOfSpeech => "pos",
+            Field::Declension => "decl",
+            Field::Conjugation => "conj",
+            Field::Gender => "gender",
+            Field::NounKind => "noun",
+            Field::VerbKind => "verb",
+        }
+    }
+
+    // the value a condition compares to, from a dictionary code or a name, ex: B, classical, 3
+    fn parse_value(&self, value: &str) -> Result<FieldValue, String> {
+        let invalid = || format!("Invalid value for {}: {}", self.as_str(), value);
+        let code = value.to_uppercase();
+
+        let field_value = match self {
+            Field::Age => {
+                let age = match Age::dict_key_to_age(value) {
+                    Age::Unknown => Age::dict_key_to_age(&code),
+                    age => age,
+                };
+                FieldValue::Rank(age_rank(age))
+            }
+            Field::Frequency => {
+                FieldValue::Rank(frequency_rank(Frequency::dict_key_to_frequency(&code)))
+            }
+            Field::Declension | Field::Conjugation => {
+                FieldValue::Rank(value.parse::<i32>().map_err(|_| invalid())?)
+            }
+            Field::Area => {
+                let area = match Area::dict_key_to_area(value) {
+                    Area::Unknown => Area::dict_key_to_area(&code),
+                    area => area,
+                };
+                FieldValue::Name(area.as_str())
+            }
+            Field::Geography => FieldValue::Name(Geography::dict_key_to_geography(&code).as_str()),
+            Field::Source => FieldValue::Name(Source::dict_key_to_source(&code).as_str()),
+            Field::PartOfSpeech => {
+                let pos = match PartOfSpeech::dict_key_to_part_of_speech(value) {
+                    PartOfSpeech::Unknown => PartOfSpeech::dict_key_to_part_of_speech(&code),
+                    pos => pos,
+                };
+                FieldValue::Name(pos.as_str())
+            }
+            Field::Gender => FieldValue::Name(Gender::dict_key_to_gender(&code).as_str()),
+            Field::NounKind => FieldValue::Name(Noun::dict_key_to_noun(&code).as_str()),
+            Field::VerbKind => FieldValue::Name(Verb::dict_key_to_verb(&code).as_str()),
+        };
+
+        // every key the dictionary does not know becomes unknown
+        if field_value == FieldValue::Name("unknown") || field_value == FieldValue::Rank(0) {
+            return Err(invalid());
+        }
+        Ok(field_value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValue {
+    // ordered fields, a higher rank is later, more frequent or a higher number
+    Rank(i32),
+    Name(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Operator {
+    const ALL: [(&'static str, Operator); 6] = [
+        ("!=", Operator::NotEqual),
+        (">=", Operator::GreaterOrEqual),
+        ("<=", Operator::LessOrEqual),
+        ("=", Operator::Equal),
+        (">", Operator::Greater),
+        ("<", Operator::Less),
+    ];
+}
+
+#[derive(Debug, Clone)]
+struct Condition {
+    field: Field,
+    operator: Operator,
+    value: FieldValue,
+}
+
+impl Condition {
+    fn parse(condition: &str) -> Result<Condition, String> {
+        // the first operator, the longer one if two start at the same place, ex: >= not >
+        let (index, symbol, operator) = Operator::ALL
+            .iter()
+            .filter_map(|(symbol, operator)| {
+                condition
+                    .find(symbol)
+                    .map(|index| (index, *symbol, *operator))
+            })
+            .min_by_key(|(index, symbol, _)| (*index, usize::MAX - symbol.len()))
+            .ok_or_else(|| format!("Invalid condition: {}, ex: freq>=B", condition))?;
+
+        let field = Field::from_name(condition[..index].trim())?;
+        let value = field.parse_value(condition[index + symbol.len()..].trim())?;
+        if matches!(value, FieldValue::Name(_))
+            && !matches!(operator, Operator::Equal | Operator::NotEqual)
+        {
+            return Err(format!(
+                "{} has no order, use = or != in {}",
+                field.as_str(),
+                condition
+            ));
+        }
+
+        Ok(Condition {
+            field,
+            operator,
+            value,
+        })
+    }
+
+    // an entry without the field never fits, ex: the gender of a verb
+    fn fits<T: Filterable>(&self, entry: &T) -> bool {
+        let value = match entry.field(self.field) {
+            Some(value) => value,
+            None => return false,
+        };
+
+        match (value, self.value) {
+            (FieldValue::Rank(value), FieldValue::Rank(wanted)) => match self.operator {
+                Operator::Equal => value == wanted,
+                Operator::NotEqual => value != wanted,
+                Operator::Less => value < wanted,
+                Operator::LessOrEqual => value <= wanted,
+                Operator::Greater => value > wanted,
+                Operator::GreaterOrEqual => value >= wanted,
+            },
+            (FieldValue::Name(value), FieldValue::Name(wanted)) => match self.operator {
+                Operator::NotEqual => value != wanted,
+                _ => value == wanted,
+            },
+            _ => false,
+        }
+    }
+}
+
+/**
+ * Conditions on the fields of an entry joined by and and or, and binds tighter than or.
+ * ex: freq>=B and pos=verb and conj=3 or pos=noun and gender=F
+ */
+#[derive(Debug, Clone, Default)]
+pub struct WhereClause {
+    // an entry fits if it fits every condition of any of the groups
+    groups: Vec<Vec<Condition>>,
+}
+
+impl WhereClause {
+    pub fn new() -> WhereClause {
+        WhereClause { groups: Vec::new() }
+    }
+
+    pub fn parse(clause: &str) -> Result<WhereClause, String> {
+        let mut groups: Vec<Vec<Condition>> = Vec::new();
+        let mut group: Vec<Condition> = Vec::new();
+        // the words of a condition can be spaced out, ex: freq >= B
+        let mut condition: Vec<&str> = Vec::new();
+
+        for word in clause.split_whitespace() {
+            let joins_groups = word.eq_ignore_ascii_case("or");
+            if !joins_groups && !word.eq_ignore_ascii_case("and") {
+                condition.push(word);
+                continue;
+            }
+
+            if condition.is_empty() {
+                return Err(format!("Missing condition before {} in {}", word, clause));
+            }
+            group.push(Condition::parse(&condition.join(" "))?);
+            condition.clear();
+
+            if joins_groups {
+                groups.push(std::mem::take(&mut group));
+            }
+        }
+
+        if condition.is_empty() {
+            return Err(format!("Missing condition at the end of {}", clause));
+        }
+        group.push(Condition::parse(&condition.join(" "))?);
+        groups.push(group);
+
+        Ok(WhereClause { groups })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    pub fn fits<T: Filterable>(&self, entry: &T) -> bool {
+        self.is_empty()
+            || self
+                .groups
+                .iter()
+                .any(|group| group.iter().all(|condition| condition.fits(entry)))
+    }
+}
+
+/**
+ * An entry of a list with the fields it has
+ */
+pub trait Filterable {
+    fn field(&self, field: Field) -> Option<FieldValue>;
+}
+
+impl Filterable for LatinWordInfo {
+    fn field(&self, field: Field) -> Option<FieldValue> {
+        match field {
+            Field::Age => Some(FieldValue::Rank(age_rank(self.info.age))),
+            Field::Area => Some(FieldValue::Name(self.info.area.as_str())),
+            Field::Geography => Some(FieldValue::Name(self.info.geo.as_str())),
+            Field::Frequency => Some(FieldValue::Rank(frequency_rank(self.info.freq))),
+            Field::Source => Some(FieldValue::Name(self.info.source.as_str())),
+            _ => grammar_field(
+                field,
+                self.pos,
+                &self.n,
+                &self.form,
+                Structure::LatinWordInfo,
+            ),
+        }
+    }
+}
+
+impl Filterable for Stem {
+    fn field(&self, field: Field) -> Option<FieldValue> {
+        grammar_field(field, self.pos, &self.n, &self.form, Structure::Stem)
+    }
+}
+
+impl Filterable for Inflection {
+    fn field(&self, field: Field) -> Option<FieldValue> {
+        grammar_field(field, self.pos, &self.n, &self.form, Structure::Inflection)
+    }
+}
+
+impl Filterable for EnglishWordInfo {
+    fn field(&self, field: Field) -> Option<FieldValue> {
+        match field {
+            Field::Frequency => Some(FieldValue::Rank(frequency_rank(self.frequency_type))),
+            Field::PartOfSpeech => Some(FieldValue::Name(self.pos.as_str())),
+            _ => None,
+        }
+    }
+}
+
+impl Filterable for Modifier {
+    fn field(&self, field: Field) -> Option<FieldValue> {
+        match field {
+            Field::PartOfSpeech => Some(FieldValue::Name(self.pos.as_str())),
+            _ => None,
+        }
+    }
+}
+
+impl Filterable for Attachment {
+    fn field(&self, field: Field) -> Option<FieldValue> {
+        match field {
+            Field::PartOfSpeech => Some(FieldValue::Name(self.pos.as_str())),
+            _ => None,
+        }
+    }
+}
+
+fn age_rank(age: Age) -> i32 {
+    match age {
+        Age::Unknown => 0,
+        age => age.as_number() as i32,
+    }
+}
+
+// 1 is the most frequent, so the rank goes the other way
+fn frequency_rank(frequency: Frequency) -> i32 {
+    match frequency {
+        Frequency::Unknown => 0,
+        frequency => 12 - frequency.as_number() as i32,
+    }
+}
+
+fn grammar_field(
+    field: Field,
+    pos: PartOfSpeech,
+    n: &Option<Vec<NValue>>,
+    form: &Form,
+    structure: Structure,
+) -> Option<FieldValue> {
+    let number = n
+        .as_ref()
+        .and_then(|n| n.first())
+        .map(|n| n.get_n_value_1());
+
+    match field {
+        Field::PartOfSpeech => Some(FieldValue::Name(pos.as_str())),
+        Field::Declension => match pos {
+            PartOfSpeech::Noun
+            | PartOfSpeech::Adjective
+            | PartOfSpeech::Pronoun
+            | PartOfSpeech::Numeral => number.map(|number| FieldValue::Rank(number as i32)),
+            _ => None,
+        },
+        Field::Conjugation => match pos {
+            PartOfSpeech::Verb | PartOfSpeech::Participle => {
+                number.map(|number| FieldValue::Rank(number as i32))
+            }
+            _ => None,
+        },
+        Field::Gender | Field::NounKind | Field::VerbKind => {
+            let long_form = long_form(form, pos, structure)?;
+            let name = match field {
+                Field::Gender => long_form.gender?.as_str(),
+                Field::NounKind => long_form.noun?.as_str(),
+                _ => long_form.verb?.as_str(),
+            };
+            Some(FieldValue::Name(name))
+        }
+        _ => None,
+    }
+}
+
+fn long_form(form: &Form, pos: PartOfSpeech, structure: Structure) -> Option<LongForm> {
+    let mut form = form.clone();
+    form.str_form_to_long_form(pos, structure);
+    match form {
+        Form::LongForm(long_form) => Some(long_form),
+        Form::StrForm(_) => None,
+    }
+}
@@ -1,11 +1,43 @@
 use crate::dictionary_structures::dictionary_values::{
-    Attachment, EnglishWordInfo, Inflection, LatinWordInfo, Modifier, Stem, UniqueLatinWordInfo,
+    Abbreviation, Attachment, EnglishIrregularForm, EnglishWordInfo, Inflection, LatinWordInfo,
+    Modifier, Stem, UniqueLatinWordInfo,
 };
+use std::collections::HashMap;
 use std::include_bytes;
 
+/**
+ * Stems and english words are generated from the dictionary, so they are numbered by their latin entry.
+ * The id is the wid followed by the place of the entry among the ones of its word, ex: 7291 is the first stem of 729.
+ */
+fn number_by_word<T>(
+    mut entries: Vec<T>,
+    places: i32,
+    wid_of: fn(&T) -> i32,
+    set_id: fn(&mut T, i32),
+) -> Vec<T> {
+    let mut counts: HashMap<i32, i32> = HashMap::new();
+    for entry in entries.iter_mut() {
+        let wid = wid_of(entry);
+        let count = counts.entry(wid).or_insert(0);
+        *count += 1;
+        set_id(entry, wid * places + *count);
+    }
+    entries
+}
+
 pub fn get_english_dictionary() -> Vec<EnglishWordInfo> {
     let english_words_json = include_bytes!("../dictionary/english_words.json");
-    serde_json::from_slice(english_words_json).unwrap()
+    number_by_word(
+        serde_json::from_slice(english_words_json).unwrap(),
+        1000,
+        |english_word| english_word.wid,
+        EnglishWordInfo::set_id,
+    )
+}
+
+pub fn get_english_irregular_forms() -> Vec<EnglishIrregularForm> {
+    let english_irregular_forms_json = include_bytes!("../dictionary/english_irregular_forms.json");
+    serde_json::from_slice(english_irregular_forms_json).unwrap()
 }
 
 pub fn get_latin_dictionary() -> Vec<LatinWordInfo> {
@@ -28,6 +60,7 @@ pub fn get_unique_latin_words() -> Vec<LatinWordInfo> {
             latin_word_info.form = word.form.clone();
             latin_word_info.info = word.info.clone();
             latin_word_info.n = word.n.clone();
+            latin_word_info.id = word.id;
             latin_word_info
         })
         .collect()
@@ -40,7 +73,12 @@ pub fn get_latin_inflections() -> Vec<Inflection> {
 
 pub fn get_latin_stems() -> Vec<Stem> {
     let latin_stems_json = include_bytes!("../dictionary/latin_stems.json");
-    serde_json::from_slice(latin_stems_json).unwrap()
+    number_by_word(
+        serde_json::from_slice(latin_stems_json).unwrap(),
+        10,
+        |stem| stem.wid,
+        Stem::set_id,
+    )
 }
 
 pub fn get_latin_prefixes() -> Vec<Modifier> {
@@ -72,3 +110,14 @@ pub fn get_latin_tickons() -> Vec<Attachment> {
     let latin_tickons_json = include_bytes!("../dictionary/latin_tickons.json");
     serde_json::from_slice(latin_tickons_json).unwrap()
 }
+
+pub fn get_latin_enclitic_exceptions() -> Vec<String> {
+    let latin_enclitic_exceptions_json =
+        include_bytes!("../dictionary/latin_enclitic_exceptions.json");
+    serde_json::from_slice(latin_enclitic_exceptions_json).unwrap()
+}
+
+pub fn get_latin_abbreviations() -> Vec<Abbreviation> {
+    let latin_abbreviations_json = include_bytes!("../dictionary/latin_abbreviations.json");
+    serde_json::from_slice(latin_abbreviations_json).unwrap()
+}
@@ -1,5 +1,6 @@
 pub mod data;
 pub mod principle_part_generator;
+pub mod sorting;
 pub mod type_translator;
 
 /**
@@ -6,7 +6,7 @@ pub fn generate_for_adjectives(
     num_type_2: i8,
     parts: Vec<String>,
     comparison: Comparison,
-) -> Vec<String> {
+) -> Result<Vec<String>, String> {
     match comparison {
         Comparison::Comparative => {
             set_principle_parts(parts, vec![("or", 1), ("or", 1), ("us", 1)], None)
@@ -48,7 +48,7 @@ pub fn generate_for_adjectives(
                 (9, 9) => {
                     set_principle_parts(parts, vec![("", 0), ("", 0), ("", 0)], Some("undeclined"))
                 }
-                _ => parts,
+                _ => Ok(parts),
             }
         }
         Comparison::Unknown => {
@@ -99,7 +99,7 @@ pub fn generate_for_adjectives(
                 (9, 9) => {
                     set_principle_parts(parts, vec![("", 0), ("", 0), ("", 0)], Some("undeclined"))
                 }
-                _ => parts,
+                _ => Ok(parts),
             }
         }
     }
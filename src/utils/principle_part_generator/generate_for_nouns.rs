@@ -6,7 +6,7 @@ pub fn generate_for_nouns(
     num_type_2: i8,
     gender: Gender,
     parts: Vec<String>,
-) -> Vec<String> {
+) -> Result<Vec<String>, String> {
     match (num_type_1, num_type_2) {
         // first declension
         (1, 1) => set_principle_parts(parts, vec![("a", 1), ("ae", 2)], None),
@@ -24,7 +24,7 @@ pub fn generate_for_nouns(
             } else if gender == Gender::Neuter {
                 set_principle_parts(parts, vec![("um", 1), ("(i)", 2)], None)
             } else {
-                parts
+                Ok(parts)
             }
         }
         (2, 5) => set_principle_parts(parts, vec![("us", 1), ("", 2)], None),
@@ -35,7 +35,8 @@ pub fn generate_for_nouns(
         (3, 1) | (3, 2) | (3, 3) | (3, 4) => {
             set_principle_parts(parts, vec![("", 1), ("is", 2)], None)
         }
-        (3, 7) | (3, 9) => set_principle_parts(parts, vec![("", 1), ("os/is", 2)], None),
+        (3, 6) => set_principle_parts(parts, vec![("", 1), ("is", 2)], None),
+        (3, 7) | (3, 8) | (3, 9) => set_principle_parts(parts, vec![("", 1), ("os/is", 2)], None),
         // fourth declension
         (4, 1) => set_principle_parts(parts, vec![("us", 1), ("us", 2)], None),
         (4, 2) => set_principle_parts(parts, vec![("u", 1), ("us", 2)], None),
@@ -45,6 +46,6 @@ pub fn generate_for_nouns(
         // special
         (9, 8) => set_principle_parts(parts, vec![("", 0), ("", 0)], Some("abbreviation")),
         (9, 9) => set_principle_parts(parts, vec![("", 0), ("", 0)], Some("undeclined")),
-        _ => parts,
+        _ => Ok(parts),
     }
 }
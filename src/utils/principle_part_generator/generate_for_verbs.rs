@@ -6,7 +6,14 @@ pub fn generate_for_verbs(
     num_type_2: i8,
     parts: Vec<String>,
     verb_type: Verb,
-) -> Vec<String> {
+) -> Result<Vec<String>, String> {
+    if parts.len() < 4 {
+        return Err(format!(
+            "A verb needs 4 stems to generate principle parts, but {} were provided",
+            parts.len()
+        ));
+    }
+
     if num_type_1 == 9 && num_type_2 == 8 {
         return set_principle_parts(
             parts,
@@ -23,190 +30,186 @@ pub fn generate_for_verbs(
         );
     }
 
+    let unsupported = || {
+        Err(format!(
+            "No principle parts for a {} verb of conjugation {} {}",
+            verb_type.as_str(),
+            num_type_1,
+            num_type_2
+        ))
+    };
+
     match verb_type {
-        Verb::Deponent => match num_type_1 {
-            1 => set_principle_parts(
-                parts,
-                vec![("or", 1), ("ari", 2), ("", 0), ("us sum", 4)],
-                None,
-            ),
-            2 => set_principle_parts(
-                parts,
-                vec![("eor", 1), ("eri", 2), ("", 0), ("us sum", 4)],
-                None,
-            ),
-            3 => {
-                if num_type_2 == 4 {
-                    set_principle_parts(
-                        parts,
-                        vec![("or", 1), ("iri", 2), ("", 0), ("us sum", 4)],
-                        None,
-                    )
-                } else {
-                    set_principle_parts(
-                        parts,
-                        vec![("or", 1), ("i", 2), ("", 0), ("us sum", 4)],
-                        None,
-                    )
-                }
-            }
-            _ => parts,
-        },
+        // hortor, hortari, hortatus sum
+        Verb::Deponent => {
+            let endings = match (num_type_1, num_type_2) {
+                (1, _) => vec![("or", 1), ("ari", 2), ("us sum", 4)],
+                (2, _) => vec![("eor", 1), ("eri", 2), ("us sum", 4)],
+                (3, 4) => vec![("or", 1), ("iri", 2), ("us sum", 4)],
+                (3, _) => vec![("or", 1), ("i", 2), ("us sum", 4)],
+                _ => return unsupported(),
+            };
+
+            set_principle_parts(parts, endings, None)
+        }
+        // audeo, audere, ausus sum
+        Verb::SemiDeponent if num_type_1 != 8 => {
+            let endings = match (num_type_1, num_type_2) {
+                (2, _) => vec![("eo", 1), ("ere", 2), ("us sum", 4)],
+                // fio, fieri, factus sum
+                (3, 3) => vec![("o", 1), ("ieri", 2), ("us sum", 4)],
+                (3, 4) => vec![("o", 1), ("ire", 2), ("us sum", 4)],
+                (3, _) => vec![("o", 1), ("ere", 2), ("us sum", 4)],
+                _ => return unsupported(),
+            };
+
+            set_principle_parts(parts, endings, None)
+        }
+        // memini, meminisse; coepi, coepisse, coeptus
         Verb::PerfectDefinite => {
-            set_principle_parts(parts, vec![("i", 3), ("isse", 3), ("us", 4), ("", 0)], None)
+            let principle_parts =
+                set_principle_parts(parts, vec![("i", 3), ("isse", 3), ("us", 4)], None)?;
+
+            Ok(without_missing_last_part(principle_parts))
         }
-        _ => {
-            if verb_type == Verb::Impersonal && parts[0].trim() == "zzz" && parts[1].trim() == "zzz"
-            {
-                set_principle_parts(
+        // oportet, oportere, oportuit
+        Verb::Impersonal => {
+            // only the perfect is used, ex: (paenitet) paenituit
+            if parts[0].trim() == "zzz" && parts[1].trim() == "zzz" {
+                return set_principle_parts(
                     parts,
-                    vec![("it", 3), ("isse", 3), ("us est", 4), ("", 0)],
+                    vec![("it", 3), ("isse", 3), ("um est", 4)],
                     None,
-                )
-            } else if verb_type == Verb::Impersonal {
-                match num_type_1 {
-                    1 => {
-                        set_principle_parts(parts, vec![("at", 1), ("", 0), ("", 0), ("", 0)], None)
-                    }
-                    2 => {
-                        set_principle_parts(parts, vec![("et", 1), ("", 0), ("", 0), ("", 0)], None)
-                    }
-                    3 => {
-                        if num_type_2 == 2 {
-                            set_principle_parts(
-                                parts,
-                                vec![("t", 1), ("", 0), ("", 0), ("", 0)],
-                                None,
-                            )
-                        } else {
-                            if parts[0].ends_with("i") {
-                                set_principle_parts(
-                                    parts,
-                                    vec![("t", 1), ("", 0), ("", 0), ("", 0)],
-                                    None,
-                                )
-                            } else {
-                                set_principle_parts(
-                                    parts,
-                                    vec![("it", 1), ("", 0), ("", 0), ("", 0)],
-                                    None,
-                                )
-                            }
-                        }
-                    }
-                    5 => {
-                        if num_type_2 == 1 {
-                            set_principle_parts(
-                                parts,
-                                vec![("est", 1), ("", 0), ("", 0), ("", 0)],
-                                None,
-                            )
-                        } else {
-                            parts
-                        }
-                    }
-                    7 => {
-                        if num_type_2 == 1 || num_type_2 == 2 {
-                            set_principle_parts(
-                                parts,
-                                vec![("t", 1), ("", 0), ("", 0), ("", 0)],
-                                None,
-                            )
-                        } else {
-                            parts
-                        }
+                );
+            }
+
+            let endings = match (num_type_1, num_type_2) {
+                (1, _) => vec![("at", 1), ("are", 2), ("it", 3), ("um est", 4)],
+                (2, _) => vec![("et", 1), ("ere", 2), ("it", 3), ("um est", 4)],
+                // refert, referre
+                (3, 2) => vec![("t", 1), ("re", 2), ("it", 3), ("um est", 4)],
+                (3, 4) => vec![("t", 1), ("ire", 2), ("it", 3), ("um est", 4)],
+                (3, _) => {
+                    if parts[0].ends_with('i') {
+                        vec![("t", 1), ("ere", 2), ("it", 3), ("um est", 4)]
+                    } else {
+                        vec![("it", 1), ("ere", 2), ("it", 3), ("um est", 4)]
                     }
-                    _ => parts,
                 }
+                // interest, interesse, interfuit
+                (5, 1) => vec![("est", 2), ("esse", 2), ("it", 3), ("", 0)],
+                // ait, inquit
+                (7, 1) => vec![("t", 1), ("", 0), ("", 0), ("", 0)],
+                (7, 2) => vec![("it", 2), ("", 0), ("", 0), ("", 0)],
+                _ => return unsupported(),
+            };
+
+            Ok(without_missing_last_part(set_principle_parts(
+                parts, endings, None,
+            )?))
+        }
+        Verb::ToBe
+        | Verb::ToBeing
+        | Verb::TakesGenitive
+        | Verb::TakesDative
+        | Verb::TakesAblative
+        | Verb::Transitive
+        | Verb::Intransitive
+        | Verb::SemiDeponent
+        | Verb::Unknown => {
+            if !(1..=8).contains(&num_type_1) || num_type_1 == 4 {
+                return unsupported();
+            }
+
+            // building array instead of each case, because lots of options / overlap
+            let mut ending_array = vec![("", 0); 4];
+
+            // first part ending
+            if num_type_1 == 2 || (num_type_1 == 8 && num_type_2 == 2) {
+                ending_array[0] = ("eo", 1);
+            } else if num_type_1 == 5 {
+                ending_array[0] = ("um", 1);
+            } else if num_type_1 == 7 && num_type_2 == 2 {
+                // inquam is built on the second stem
+                ending_array[0] = ("am", 2);
             } else {
-                // building array instead of each case, because lots of options / overlap
-                let mut ending_array = vec![("", 0); 4];
-
-                // first part ending
-                if num_type_1 == 2 {
-                    ending_array[0] = ("eo", 1);
-                } else if num_type_1 == 5 {
-                    ending_array[0] = ("um", 1);
-                } else if num_type_1 == 7 && num_type_2 == 2 {
-                    ending_array[0] = ("am", 1);
-                } else {
-                    ending_array[0] = ("o", 1);
-                }
+                ending_array[0] = ("o", 1);
+            }
 
-                // second part ending
-                match num_type_1 {
-                    1 => ending_array[1] = ("are", 2),
-                    2 => ending_array[1] = ("ere", 2),
-                    3 => {
-                        match num_type_2 {
-                            2 => ending_array[1] = ("re", 2),
-                            3 => {
-                                // special case for fio, fieri: it follows the usual
-                                // conjugation everywhere except for present infinitive
-                                if parts[1].trim() == "f" {
-                                    ending_array[1] = ("ieri", 2);
-                                } else {
-                                    ending_array[1] = ("eie", 2);
-                                }
+            // second part ending
+            match num_type_1 {
+                1 => ending_array[1] = ("are", 2),
+                2 => ending_array[1] = ("ere", 2),
+                3 => {
+                    match num_type_2 {
+                        2 => ending_array[1] = ("re", 2),
+                        3 => {
+                            // special case for fio, fieri: it follows the usual
+                            // conjugation everywhere except for present infinitive
+                            if parts[1].trim() == "f" {
+                                ending_array[1] = ("ieri", 2);
+                            } else {
+                                ending_array[1] = ("eie", 2);
                             }
-                            4 => ending_array[1] = ("ire", 2),
-                            _ => ending_array[1] = ("ere", 2),
-                        }
-                    }
-                    5 => {
-                        if num_type_2 == 1 {
-                            ending_array[1] = ("esse", 2);
-                        } else if num_type_2 == 2 {
-                            ending_array[1] = ("e", 1); // uses first part
-                        }
-                    }
-                    6 => {
-                        if num_type_2 == 1 {
-                            ending_array[1] = ("ere", 2);
-                        } else if num_type_2 == 2 {
-                            ending_array[1] = ("le", 2);
                         }
+                        4 => ending_array[1] = ("ire", 2),
+                        _ => ending_array[1] = ("ere", 2),
                     }
-                    7 => {
-                        if num_type_2 == 2 {
-                            ending_array[1] = ("iam", 2);
-                        } else if num_type_2 == 3 {
-                            ending_array[1] = ("se", 2);
-                        }
+                }
+                5 => {
+                    if num_type_2 == 1 {
+                        ending_array[1] = ("esse", 2);
+                    } else if num_type_2 == 2 {
+                        ending_array[1] = ("e", 1); // uses first part
                     }
-                    8 => {
-                        match num_type_2 {
-                            1 => ending_array[1] = ("are", 2),
-                            4 => ending_array[1] = ("ire", 2),
-                            _ => ending_array[1] = ("ere", 2), // 2 & 3 & everything else
-                        }
+                }
+                6 => {
+                    if num_type_2 == 1 {
+                        ending_array[1] = ("re", 2);
+                    } else if num_type_2 == 2 {
+                        ending_array[1] = ("le", 2);
                     }
-                    _ => ending_array[1] = ("", 0),
                 }
-
-                // third and fourth part endings
-                if verb_type == Verb::Impersonal {
-                    // might be a 2 setting here, but it should be covered earlier
-                    ending_array[3] = ("us est", 4);
-                } else if verb_type == Verb::SemiDeponent {
-                    ending_array[3] = ("us sum", 4);
-                } else if num_type_1 == 5 && num_type_2 == 1 {
-                    ending_array[2] = ("i", 3);
-                    ending_array[3] = ("urus", 4);
-                } else if num_type_1 == 8 {
-                    // additional forms, undefined
-                } else {
-                    ending_array[2] = ("i", 3);
-                    ending_array[3] = ("us", 4);
+                7 => {
+                    if num_type_2 == 3 {
+                        ending_array[1] = ("se", 2);
+                    }
                 }
-
-                // small correction
-                if num_type_1 == 6 && num_type_2 == 1 {
-                    ending_array[2] = ("(ii)", 3);
+                8 => {
+                    match num_type_2 {
+                        1 => ending_array[1] = ("are", 2),
+                        4 => ending_array[1] = ("ire", 2),
+                        _ => ending_array[1] = ("ere", 2), // 2 & 3 & everything else
+                    }
                 }
+                _ => ending_array[1] = ("", 0),
+            }
 
-                set_principle_parts(parts, ending_array, None)
+            // third and fourth part endings
+            if num_type_1 == 5 && num_type_2 == 1 {
+                ending_array[2] = ("i", 3);
+                ending_array[3] = ("urus", 4);
+            } else if num_type_1 == 8 {
+                // additional forms, undefined
+            } else if num_type_1 == 6 && num_type_2 == 1 {
+                // eo, ire, ivi(ii), itus
+                ending_array[2] = ("i(ii)", 3);
+                ending_array[3] = ("us", 4);
+            } else {
+                ending_array[2] = ("i", 3);
+                ending_array[3] = ("us", 4);
             }
+
+            set_principle_parts(parts, ending_array, None)
         }
     }
 }
+
+// verbs that lack their last part are cited without it, ex: memini, meminisse
+fn without_missing_last_part(mut principle_parts: Vec<String>) -> Vec<String> {
+    while principle_parts.len() > 1 && principle_parts.last().is_some_and(|part| part == "---") {
+        principle_parts.pop();
+    }
+
+    principle_parts
+}
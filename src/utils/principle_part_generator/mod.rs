@@ -30,37 +30,24 @@ pub fn generate_principle_parts(
     comparison: Option<Comparison>,
     verb_type: Option<Verb>,
     numeral_type: Option<Numeral>,
-) -> Vec<String> {
+) -> Result<Vec<String>, String> {
     match generator {
-        Generator::Noun => {
-            if gender.is_none() {
-                println!("A gender is required for generating principle parts for a noun, but none was provided");
-                std::process::exit(0);
-            }
-
-            generate_for_nouns(num_type_1, num_type_2, gender.unwrap(), parts)
-        }
-        Generator::Adjective => {
-            if comparison.is_none() {
-                println!("A comparison is required for generating principle parts for an adjective, but none was provided");
-                std::process::exit(0);
-            }
-            generate_for_adjectives(num_type_1, num_type_2, parts, comparison.unwrap())
-        }
-        Generator::Verb => {
-            if verb_type.is_none() {
-                println!("A verb type is required for generating principle parts for a verb, but none was provided");
-                std::process::exit(0);
-            }
-            generate_for_verbs(num_type_1, num_type_2, parts, verb_type.unwrap())
-        }
-        Generator::Numeral => {
-            if numeral_type.is_none() {
-                println!("A numeral type is required for generating principle parts for a numeral, but none was provided");
-                std::process::exit(0);
-            }
-            generate_for_numerals(num_type_1, num_type_2, parts, numeral_type.unwrap())
-        }
+        Generator::Noun => match gender {
+            Some(gender) => generate_for_nouns(num_type_1, num_type_2, gender, parts),
+            None => Err("A gender is required for generating principle parts for a noun, but none was provided".to_string()),
+        },
+        Generator::Adjective => match comparison {
+            Some(comparison) => generate_for_adjectives(num_type_1, num_type_2, parts, comparison),
+            None => Err("A comparison is required for generating principle parts for an adjective, but none was provided".to_string()),
+        },
+        Generator::Verb => match verb_type {
+            Some(verb_type) => generate_for_verbs(num_type_1, num_type_2, parts, verb_type),
+            None => Err("A verb type is required for generating principle parts for a verb, but none was provided".to_string()),
+        },
+        Generator::Numeral => match numeral_type {
+            Some(numeral_type) => generate_for_numerals(num_type_1, num_type_2, parts, numeral_type),
+            None => Err("A numeral type is required for generating principle parts for a numeral, but none was provided".to_string()),
+        },
         Generator::Pronoun => generate_for_pronouns(num_type_1, num_type_2, parts),
     }
 }
@@ -69,15 +56,14 @@ pub fn set_principle_parts(
     parts: Vec<String>,
     endings: Vec<(&str, i8)>,
     special_case: Option<&str>,
-) -> Vec<String> {
+) -> Result<Vec<String>, String> {
     let mut principle_parts = Vec::new();
 
     if endings.iter().all(|x| x.0 == "" && x.1 == 0) {
-        if special_case.is_none() {
-            println!("No Endings or Special Case provided");
-            std::process::exit(0);
-        }
-        return vec![parts[0].clone() + " | " + special_case.unwrap_or("")];
+        return match special_case {
+            Some(special_case) => Ok(vec![parts[0].clone() + " | " + special_case]),
+            None => Err("No endings or special case provided".to_string()),
+        };
     }
 
     // number in ending is referring to principle part number to add ending to
@@ -109,5 +95,5 @@ pub fn set_principle_parts(
         part.push_str(ending_to_add_to_part);
         principle_parts.push(part);
     }
-    principle_parts
+    Ok(principle_parts)
 }
@@ -0,0 +1,303 @@
+use crate::dictionary_structures::dictionary_keys::{Age, Frequency, PartOfSpeech};
+use crate::dictionary_structures::dictionary_values::{EnglishWordInfo, LatinWordInfo};
+use crate::translators::english_to_latin::EnglishTranslationInfo;
+use crate::translators::latin_to_english::LatinTranslationInfo;
+use std::cmp::Ordering;
+
+/**
+ * What results can be sorted by
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortKey {
+    // how well the result matched, ex: the fewest tricks for latin, the most used gloss for english
+    Score,
+    Frequency,
+    Age,
+    PartOfSpeech,
+    Lemma,
+    // the number of forms the word has, ex: a verb has more than an adverb
+    Forms,
+    Id,
+}
+
+impl SortKey {
+    pub fn from_name(key: &str) -> Result<SortKey, String> {
+        match key {
+            "score" => Ok(SortKey::Score),
+            "frequency" | "freq" => Ok(SortKey::Frequency),
+            "age" => Ok(SortKey::Age),
+            "pos" | "part_of_speech" => Ok(SortKey::PartOfSpeech),
+            "lemma" => Ok(SortKey::Lemma),
+            "forms" => Ok(SortKey::Forms),
+            "id" => Ok(SortKey::Id),
+            _ => Err(format!(
+                "Invalid sort key: {}, use score, frequency, age, pos, lemma, forms or id",
+                key
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortKey::Score => "score",
+            SortKey::Frequency => "frequency",
+            SortKey::Age => "age",
+            SortKey::PartOfSpeech => "pos",
+            SortKey::Lemma => "lemma",
+            SortKey::Forms => "forms",
+            SortKey::Id => "id",
+        }
+    }
+
+    // a score needs a translation to compare against, and building the forms of a whole table is too slow
+    pub fn sorts_lists(&self) -> bool {
+        !matches!(self, SortKey::Score | SortKey::Forms)
+    }
+
+    // the ones with more first unless asked otherwise, ex: the most frequent words first
+    fn descending_by_default(&self) -> bool {
+        matches!(self, SortKey::Score | SortKey::Frequency | SortKey::Forms)
+    }
+}
+
+/**
+ * A key and the direction to sort it in
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct SortBy {
+    pub key: SortKey,
+    pub descending: bool,
+}
+
+impl SortBy {
+    pub fn new(key: SortKey) -> SortBy {
+        SortBy {
+            key,
+            descending: key.descending_by_default(),
+        }
+    }
+}
+
+/**
+ * Parses keys separated by commas, each with an optional direction, ex: frequency,age:desc,lemma
+ * Score, frequency and forms are descending and the others ascending if no direction is given.
+ */
+pub fn parse_sort_by(keys: &str) -> Result<Vec<SortBy>, String> {
+    keys.split(',')
+        .map(|key| {
+            let (key, direction) = match key.trim().split_once(':') {
+                Some((key, direction)) => (key, Some(direction)),
+                None => (key.trim(), None),
+            };
+            let mut sort_by = SortBy::new(SortKey::from_name(key)?);
+            match direction {
+                Some("asc") => sort_by.descending = false,
+                Some("desc") => sort_by.descending = true,
+                Some(direction) => {
+                    return Err(format!(
+                        "Invalid sort direction: {}, use asc or desc",
+                        direction
+                    ))
+                }
+                None => {}
+            }
+            Ok(sort_by)
+        })
+        .collect()
+}
+
+/**
+ * Something with a value for every sort key but forms, which the caller counts.
+ * Latin words must still have their stems, the lemma is built from them.
+ */
+pub trait Sortable {
+    fn score(&self) -> f64;
+    fn frequency(&self) -> Frequency;
+    fn age(&self) -> Age;
+    fn pos(&self) -> PartOfSpeech;
+    fn lemma(&self) -> String;
+    fn id(&self) -> i32;
+}
+
+impl Sortable for LatinWordInfo {
+    // a word of a list has nothing to be scored against, lists do not sort by score
+    fn score(&self) -> f64 {
+        0.0
+    }
+
+    fn frequency(&self) -> Frequency {
+        self.info.freq
+    }
+
+    fn age(&self) -> Age {
+        self.info.age
+    }
+
+    fn pos(&self) -> PartOfSpeech {
+        self.pos
+    }
+
+    fn lemma(&self) -> String {
+        LatinWordInfo::lemma(self)
+    }
+
+    fn id(&self) -> i32 {
+        self.id
+    }
+}
+
+impl Sortable for EnglishWordInfo {
+    fn score(&self) -> f64 {
+        self.true_frequency.unwrap_or(0) as f64
+    }
+
+    fn frequency(&self) -> Frequency {
+        self.frequency_type
+    }
+
+    fn age(&self) -> Age {
+        Age::Unknown
+    }
+
+    fn pos(&self) -> PartOfSpeech {
+        self.pos
+    }
+
+    fn lemma(&self) -> String {
+        self.orth.to_string()
+    }
+
+    fn id(&self) -> i32 {
+        self.id
+    }
+}
+
+impl Sortable for LatinTranslationInfo {
+    // the fewer changes needed to find the word the better, ex: no tricks or prefixes
+    fn score(&self) -> f64 {
+        let tricks = self.tricks.as_ref().map_or(0, |tricks| tricks.len());
+        let modifiers = self
+            .word
+            .modifiers
+            .as_ref()
+            .map_or(0, |modifiers| modifiers.len());
+        -((tricks + modifiers) as f64)
+    }
+
+    fn frequency(&self) -> Frequency {
+        self.word.frequency()
+    }
+
+    fn age(&self) -> Age {
+        self.word.age()
+    }
+
+    fn pos(&self) -> PartOfSpeech {
+        self.word.pos()
+    }
+
+    fn lemma(&self) -> String {
+        self.word.lemma()
+    }
+
+    fn id(&self) -> i32 {
+        self.word.id()
+    }
+}
+
+impl Sortable for EnglishTranslationInfo {
+    fn score(&self) -> f64 {
+        self.word.score()
+    }
+
+    fn frequency(&self) -> Frequency {
+        self.translation.frequency()
+    }
+
+    fn age(&self) -> Age {
+        self.translation.age()
+    }
+
+    fn pos(&self) -> PartOfSpeech {
+        self.translation.pos()
+    }
+
+    fn lemma(&self) -> String {
+        self.translation.lemma()
+    }
+
+    fn id(&self) -> i32 {
+        self.translation.id()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum SortValue {
+    Number(f64),
+    Text(String),
+}
+
+fn sort_value<T: Sortable>(entry: &T, key: SortKey, form_count: fn(&T) -> usize) -> SortValue {
+    match key {
+        SortKey::Score => SortValue::Number(entry.score()),
+        // 1 is the most frequent
+        SortKey::Frequency => SortValue::Number(-(entry.frequency().as_number() as f64)),
+        SortKey::Age => SortValue::Number(entry.age().as_number() as f64),
+        SortKey::PartOfSpeech => SortValue::Text(entry.pos().as_str().to_string()),
+        SortKey::Lemma => SortValue::Text(entry.lemma().to_lowercase()),
+        SortKey::Forms => SortValue::Number(form_count(entry) as f64),
+        SortKey::Id => SortValue::Number(entry.id() as f64),
+    }
+}
+
+/**
+ * Compares the values of two entries key by key, the first key that differs decides
+ */
+fn compare(a: &[SortValue], b: &[SortValue], sort_by: &[SortBy]) -> Ordering {
+    for (index, sort) in sort_by.iter().enumerate() {
+        let ordering = match (&a[index], &b[index]) {
+            (SortValue::Number(a), SortValue::Number(b)) => a.total_cmp(b),
+            (SortValue::Text(a), SortValue::Text(b)) => a.cmp(b),
+            _ => Ordering::Equal,
+        };
+        let ordering = if sort.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/**
+ * Sorts by each key in turn, entries that are the same for every key keep their order.
+ * The values are found once per entry, some are slow to build, ex: forms
+ * The forms are counted by the caller, ex: |word| all_forms(word).len()
+ */
+pub fn sort_entries<T: Sortable>(
+    entries: Vec<T>,
+    sort_by: &[SortBy],
+    form_count: fn(&T) -> usize,
+) -> Vec<T> {
+    if sort_by.is_empty() {
+        return entries;
+    }
+
+    let mut keyed: Vec<(Vec<SortValue>, T)> = entries
+        .into_iter()
+        .map(|entry| {
+            let values = sort_by
+                .iter()
+                .map(|sort| sort_value(&entry, sort.key, form_count))
+                .collect();
+            (values, entry)
+        })
+        .collect();
+    keyed.sort_by(|(a, _), (b, _)| compare(a, b, sort_by));
+
+    keyed.into_iter().map(|(_, entry)| entry).collect()
+}
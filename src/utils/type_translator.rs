@@ -11,9 +11,41 @@ pub fn translate_type(number_types: NValue, pos: PartOfSpeech) -> String {
         _ => "unknown".to_string(),
     };
 
+    if is_greek_type(&number_types, pos) {
+        return format!("{} greek", type_name);
+    }
+
     type_name
 }
 
+/**
+ * Greek nouns and adjectives have their own variants of the 1st, 2nd and 3rd declensions.
+ * ex: epitome (1 6), Aeneas (1 8), Androgeos (2 7), Achilles (3 8), acanthicos (2 6 adj)
+ */
+pub fn is_greek_type(number_types: &NValue, pos: PartOfSpeech) -> bool {
+    let num_type_1 = number_types.get_n_value_1();
+    let num_type_2 = number_types.get_n_value_2();
+
+    match pos {
+        PartOfSpeech::Noun => matches!((num_type_1, num_type_2), (1..=3, 6..=9)),
+        PartOfSpeech::Adjective => matches!((num_type_1, num_type_2), (2, 6..=8) | (3, 6)),
+        _ => false,
+    }
+}
+
+/**
+ * The same check for the n of a stem or inflection, which has the two numbers apart, ex: [1, 8]
+ */
+pub fn is_greek_n(n: &[NValue], pos: PartOfSpeech) -> bool {
+    match n {
+        [n_1, n_2, ..] => is_greek_type(
+            &NValue::IntInt(n_1.get_n_value_1(), n_2.get_n_value_2()),
+            pos,
+        ),
+        _ => false,
+    }
+}
+
 fn translate_noun_type(num_type_1: i8) -> String {
     let type_name = match num_type_1 {
         1 => "1st declension",